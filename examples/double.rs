@@ -15,16 +15,13 @@ use cursive_calendar_view::{CalendarView, EnglishLocale, ViewMode};
 fn main() {
     let mut siv = cursive::default();
 
-    let mut calendar_a = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
-    calendar_a.set_highest_view_mode(ViewMode::Year);
-    calendar_a.set_earliest_date(Some(Utc.ymd(2017, 1, 1)));
-    calendar_a.set_latest_date(Some(Utc.ymd(2017, 12, 31)));
-    calendar_a.set_show_iso_weeks(true);
-
-    let mut calendar_b = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
-    calendar_b.set_highest_view_mode(ViewMode::Year);
-    calendar_b.set_earliest_date(Some(Utc.ymd(2017, 1, 1)));
-    calendar_b.set_latest_date(Some(Utc.ymd(2017, 12, 31)));
+    let builder = CalendarView::<Utc, EnglishLocale>::builder()
+        .highest_view_mode(ViewMode::Year)
+        .earliest_date(Some(Utc.ymd(2017, 1, 1)))
+        .latest_date(Some(Utc.ymd(2017, 12, 31)));
+
+    let calendar_a = builder.build(Utc.ymd(2017, 7, 26)).show_iso_weeks(true);
+    let calendar_b = builder.build(Utc.ymd(2017, 7, 26));
 
     let mut layout = LinearLayout::new(Orientation::Horizontal);
     layout.add_child(calendar_a);
@@ -0,0 +1,165 @@
+// STD Dependencies -----------------------------------------------------------
+use std::ops::RangeBounds;
+use std::sync::Arc;
+
+// External Dependencies ------------------------------------------------------
+use chrono::prelude::*;
+
+// Internal Dependencies ------------------------------------------------------
+use crate::cursive::Cursive;
+use crate::{
+    clone_bound, CalendarState, CalendarStyle, CalendarView, DynLocale, KeyBindings, Locale,
+    ViewMode, WeekDay, WeekNumbering,
+};
+
+/// A callback applying an option to a view.
+///
+/// This is an internal type used to improve readability.
+type OptionCallback<T, L> = Arc<dyn Fn(&mut CalendarView<T, L>) + Send + Sync>;
+
+/// Builder collecting the options of a [`CalendarView`](struct.CalendarView.html),
+/// e.g. to construct several identically configured views.
+///
+/// Created via [`CalendarView::builder`](struct.CalendarView.html#method.builder).
+///
+/// Commonly used options have typed methods, any other setter of the view
+/// can be applied via [`option`](#method.option).
+pub struct CalendarViewBuilder<T: TimeZone, L: Locale> {
+    options: Vec<OptionCallback<T, L>>,
+}
+
+impl<T: TimeZone + Send + Sync + 'static, L: Locale + Send + Sync + 'static>
+    CalendarViewBuilder<T, L>
+where
+    T::Offset: Send + Sync,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            options: Vec::new(),
+        }
+    }
+
+    /// Adds a callback which configures the view, e.g. by calling one of
+    /// its setters.
+    pub fn option<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&mut CalendarView<T, L>) + Send + Sync + 'static,
+    {
+        self.options.push(Arc::new(f));
+        self
+    }
+
+    /// Sets the lowest view mode the views can be in.
+    pub fn lowest_view_mode(self, mode: ViewMode) -> Self {
+        self.option(move |v| v.set_lowest_view_mode(mode))
+    }
+
+    /// Sets the highest view mode the views can be in.
+    pub fn highest_view_mode(self, mode: ViewMode) -> Self {
+        self.option(move |v| v.set_highest_view_mode(mode))
+    }
+
+    /// Sets and limits the earliest date selectable by the views.
+    pub fn earliest_date(self, date: Option<Date<T>>) -> Self {
+        self.option(move |v| v.set_earliest_date(date.clone()))
+    }
+
+    /// Sets and limits the latest date selectable by the views.
+    pub fn latest_date(self, date: Option<Date<T>>) -> Self {
+        self.option(move |v| v.set_latest_date(date.clone()))
+    }
+
+    /// Sets the day on which weeks start.
+    pub fn week_start(self, day: WeekDay) -> Self {
+        self.option(move |v| v.set_week_start(day))
+    }
+
+    /// Show or hide ISO week numbers.
+    pub fn show_iso_weeks(self, show: bool) -> Self {
+        self.option(move |v| v.set_show_iso_weeks(show))
+    }
+
+    /// Sets the week numbering scheme shown next to the month grid.
+    pub fn week_numbering(self, numbering: WeekNumbering) -> Self {
+        self.option(move |v| v.set_week_numbering(numbering))
+    }
+
+    /// Sets and limits the earliest and latest date selectable by the views
+    /// from a range, e.g. `start..=end`.
+    pub fn date_range<R: RangeBounds<Date<T>>>(self, range: R) -> Self {
+        let start = clone_bound(range.start_bound());
+        let end = clone_bound(range.end_bound());
+        self.option(move |v| v.set_date_range((start.clone(), end.clone())))
+    }
+
+    /// Switches the localization of the views at runtime.
+    pub fn locale<D: Into<DynLocale>>(self, locale: D) -> Self {
+        let locale = locale.into();
+        self.option(move |v| v.set_locale(locale.clone()))
+    }
+
+    /// Sets the colors used to draw the views.
+    pub fn style(self, style: CalendarStyle) -> Self {
+        self.option(move |v| v.set_style(style))
+    }
+
+    /// Sets the events used for keyboard navigation.
+    pub fn key_bindings(self, bindings: KeyBindings) -> Self {
+        self.option(move |v| v.set_key_bindings(bindings.clone()))
+    }
+
+    /// Sets a callback to be used when `<Enter>` is pressed to select a date.
+    pub fn on_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &Date<T>) + Send + Sync + 'static,
+    {
+        let cb = Arc::new(cb);
+        self.option(move |v| {
+            let cb = cb.clone();
+            v.set_on_submit(move |s, date| cb(s, date));
+        })
+    }
+
+    /// Sets a callback to be used when a new date is visually selected.
+    pub fn on_select<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &Date<T>) + Send + Sync + 'static,
+    {
+        let cb = Arc::new(cb);
+        self.option(move |v| {
+            let cb = cb.clone();
+            v.set_on_select(move |s, date| cb(s, date));
+        })
+    }
+
+    /// Sets a callback to be used whenever the selected date, the visually
+    /// selected date or the view mode changed in response to an event.
+    pub fn on_change<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &CalendarState<T>) + Send + Sync + 'static,
+    {
+        let cb = Arc::new(cb);
+        self.option(move |v| {
+            let cb = cb.clone();
+            v.set_on_change(move |s, state| cb(s, state));
+        })
+    }
+
+    /// Creates a new view with all collected options applied, in the order
+    /// they were added.
+    pub fn build(&self, today: Date<T>) -> CalendarView<T, L> {
+        let mut view = CalendarView::new(today);
+        for option in &self.options {
+            option(&mut view);
+        }
+        view
+    }
+}
+
+impl<T: TimeZone, L: Locale> Clone for CalendarViewBuilder<T, L> {
+    fn clone(&self) -> Self {
+        Self {
+            options: self.options.clone(),
+        }
+    }
+}
@@ -0,0 +1,218 @@
+// STD Dependencies -----------------------------------------------------------
+use std::sync::Arc;
+
+// External Dependencies ------------------------------------------------------
+use chrono::prelude::*;
+use chrono::Duration;
+
+use crate::cursive::direction::Direction;
+use crate::cursive::event::{Callback, Event, EventResult};
+use crate::cursive::theme::ColorStyle;
+use crate::cursive::vec::Vec2;
+use crate::cursive::view::{CannotFocus, View};
+use crate::cursive::With;
+use crate::cursive::{Cursive, Printer};
+
+// Internal Dependencies ------------------------------------------------------
+use crate::{CalendarView, KeyAction, Locale};
+
+/// A callback taking a date and time as parameter.
+///
+/// This is an internal type used to improve readability.
+type DateTimeCallback<T> = Arc<dyn Fn(&mut Cursive, &DateTime<T>) + Send + Sync>;
+
+/// Fields of the time spinner of a [`DateTimeCalendarView`](struct.DateTimeCalendarView.html).
+#[derive(Copy, Clone, PartialEq)]
+enum TimeField {
+    Hour,
+    Minute,
+}
+
+/// A [`CalendarView`](struct.CalendarView.html) which asks for a time of day
+/// after a date has been submitted.
+///
+/// The time is edited in a `HH:MM` spinner drawn below the calendar. The
+/// navigation keys of the wrapped view move between the hour and minute
+/// fields and adjust them, submitting again commits the date and time while
+/// ascending returns to the calendar.
+///
+/// All remaining configuration is available through
+/// [`inner`](#method.inner) and [`inner_mut`](#method.inner_mut).
+pub struct DateTimeCalendarView<T: TimeZone, L: Locale> {
+    inner: CalendarView<T, L>,
+    time: NaiveTime,
+    field: TimeField,
+    editing_time: bool,
+    on_submit: Option<DateTimeCallback<T>>,
+}
+
+impl<T: TimeZone + Send + Sync + 'static, L: Locale + Send + Sync + 'static>
+    DateTimeCalendarView<T, L>
+where
+    T::Offset: Send + Sync,
+{
+    /// Creates new `DateTimeCalendarView`.
+    pub fn new(today: Date<T>) -> Self {
+        Self {
+            inner: CalendarView::new(today),
+            time: NaiveTime::MIN,
+            field: TimeField::Hour,
+            editing_time: false,
+            on_submit: None,
+        }
+    }
+
+    /// Returns the wrapped [`CalendarView`](struct.CalendarView.html).
+    pub fn inner(&self) -> &CalendarView<T, L> {
+        &self.inner
+    }
+
+    /// Returns the wrapped [`CalendarView`](struct.CalendarView.html) mutably.
+    pub fn inner_mut(&mut self) -> &mut CalendarView<T, L> {
+        &mut self.inner
+    }
+
+    /// Returns the currently selected date and time of this view.
+    ///
+    /// Returns `None` if the time does not exist on the selected date, e.g.
+    /// during a daylight saving time transition.
+    pub fn date_time(&self) -> Option<DateTime<T>> {
+        self.inner.date().and_time(self.time)
+    }
+
+    /// Returns whether the time spinner is currently active.
+    pub fn is_editing_time(&self) -> bool {
+        self.editing_time
+    }
+
+    /// Sets the currently selected time of this view.
+    ///
+    /// Seconds are discarded.
+    pub fn set_time(&mut self, time: NaiveTime) {
+        self.time = NaiveTime::from_hms_opt(time.hour(), time.minute(), 0).unwrap_or(time);
+    }
+
+    /// Sets the currently selected time of this view.
+    ///
+    /// Chainable variant.
+    pub fn time(self, time: NaiveTime) -> Self {
+        self.with(|v| v.set_time(time))
+    }
+
+    /// Sets a callback to be used when a date and time have been selected.
+    pub fn set_on_submit<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &DateTime<T>) + Send + Sync + 'static,
+    {
+        self.on_submit = Some(Arc::new(move |s, date_time| cb(s, date_time)));
+    }
+
+    /// Sets a callback to be used when a date and time have been selected.
+    ///
+    /// Chainable variant.
+    pub fn on_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &DateTime<T>) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_submit(cb))
+    }
+
+    fn time_event(&mut self, event: Event) -> EventResult {
+        let step = match self.field {
+            TimeField::Hour => Duration::hours(1),
+            TimeField::Minute => Duration::minutes(1),
+        };
+
+        match self.inner.key_bindings.action(&event) {
+            Some(KeyAction::MoveUp) => self.time = self.time.overflowing_add_signed(step).0,
+            Some(KeyAction::MoveDown) => self.time = self.time.overflowing_sub_signed(step).0,
+            Some(KeyAction::MovePrev) => self.field = TimeField::Hour,
+            Some(KeyAction::MoveNext) => self.field = TimeField::Minute,
+            Some(KeyAction::Ascend) => self.editing_time = false,
+            Some(KeyAction::Submit) => {
+                self.editing_time = false;
+                let date_time = self.date_time();
+                return EventResult::Consumed(match (date_time, self.on_submit.clone()) {
+                    (Some(date_time), Some(cb)) => {
+                        Some(Callback::from_fn(move |s| cb(s, &date_time)))
+                    }
+                    _ => None,
+                });
+            }
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Consumed(None)
+    }
+
+    fn field_color(&self, field: TimeField, focused: bool) -> ColorStyle {
+        let style = self.inner.get_style();
+        if !self.editing_time {
+            style.adjacent
+        } else if self.field != field {
+            style.normal
+        } else if focused {
+            style.cursor
+        } else {
+            style.selected
+        }
+    }
+}
+
+impl<T: TimeZone + Send + Sync + 'static, L: Locale + Send + Sync + 'static> View
+    for DateTimeCalendarView<T, L>
+where
+    T::Offset: Send + Sync,
+{
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        let calendar_size = printer.size.saturating_sub((0, 2));
+        self.inner.draw(&printer.cropped(calendar_size));
+
+        // Draw Time Spinner
+        let offset = self.inner.content_offset(calendar_size);
+        let x = offset.x + self.inner.size.x.saturating_sub(5) / 2;
+        let y = offset.y + self.inner.size.y + 1;
+        printer.with_color(
+            self.field_color(TimeField::Hour, printer.focused),
+            |printer| {
+                printer.print((x, y), &format!("{:02}", self.time.hour()));
+            },
+        );
+        printer.print((x + 2, y), ":");
+        printer.with_color(
+            self.field_color(TimeField::Minute, printer.focused),
+            |printer| {
+                printer.print((x + 3, y), &format!("{:02}", self.time.minute()));
+            },
+        );
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        self.inner.required_size(constraint.saturating_sub((0, 2))) + (0, 2)
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.inner.layout(size.saturating_sub((0, 2)));
+    }
+
+    fn needs_relayout(&self) -> bool {
+        self.inner.needs_relayout()
+    }
+
+    fn take_focus(&mut self, source: Direction) -> Result<EventResult, CannotFocus> {
+        self.inner.take_focus(source)
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if self.editing_time && event != Event::FocusLost {
+            return self.time_event(event);
+        }
+
+        self.inner.submitted = false;
+        let result = self.inner.on_event(event);
+        if self.inner.submitted {
+            self.editing_time = true;
+            self.field = TimeField::Hour;
+        }
+        result
+    }
+}
@@ -0,0 +1,84 @@
+// External Dependencies ------------------------------------------------------
+use crate::cursive::event::{Event, Key};
+use crate::cursive::With;
+
+/// Enumeration of all keyboard actions supported by a [`CalendarView`](struct.CalendarView.html).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum KeyAction {
+    /// Moves the cursor to the previous day, month or year.
+    MovePrev,
+    /// Moves the cursor to the next day, month or year.
+    MoveNext,
+    /// Moves the cursor up by one row.
+    MoveUp,
+    /// Moves the cursor down by one row.
+    MoveDown,
+    /// Moves the cursor back by one page, e.g. to the previous month.
+    PageBack,
+    /// Moves the cursor forward by one page, e.g. to the next month.
+    PageForward,
+    /// Switches to the next higher view mode.
+    Ascend,
+    /// Submits the cursor position or switches to the next lower view mode.
+    Submit,
+}
+
+/// Mapping of events to the [`KeyAction`](enum.KeyAction.html)s of a
+/// [`CalendarView`](struct.CalendarView.html).
+///
+/// Multiple events can be bound to the same action, e.g. to add `h`, `j`,
+/// `k` and `l` next to the arrow keys.
+#[derive(Clone)]
+pub struct KeyBindings {
+    bindings: Vec<(Event, KeyAction)>,
+}
+
+impl KeyBindings {
+    /// Creates a new mapping without any bindings.
+    pub fn empty() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Binds the given event to an action, replacing any previous binding
+    /// of the event.
+    pub fn bind(&mut self, event: Event, action: KeyAction) {
+        self.unbind(&event);
+        self.bindings.push((event, action));
+    }
+
+    /// Binds the given event to an action.
+    ///
+    /// Chainable variant.
+    pub fn binding(self, event: Event, action: KeyAction) -> Self {
+        self.with(|b| b.bind(event, action))
+    }
+
+    /// Removes the binding of the given event.
+    pub fn unbind(&mut self, event: &Event) {
+        self.bindings.retain(|(e, _)| e != event);
+    }
+
+    /// Returns the action the given event is bound to.
+    pub fn action(&self, event: &Event) -> Option<KeyAction> {
+        self.bindings
+            .iter()
+            .find(|(e, _)| e == event)
+            .map(|(_, action)| *action)
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self::empty()
+            .binding(Event::Key(Key::Left), KeyAction::MovePrev)
+            .binding(Event::Key(Key::Right), KeyAction::MoveNext)
+            .binding(Event::Key(Key::Up), KeyAction::MoveUp)
+            .binding(Event::Key(Key::Down), KeyAction::MoveDown)
+            .binding(Event::Key(Key::PageUp), KeyAction::PageBack)
+            .binding(Event::Key(Key::PageDown), KeyAction::PageForward)
+            .binding(Event::Key(Key::Backspace), KeyAction::Ascend)
+            .binding(Event::Key(Key::Enter), KeyAction::Submit)
+    }
+}
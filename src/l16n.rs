@@ -1,5 +1,9 @@
+// STD Dependencies -----------------------------------------------------------
+use std::marker::PhantomData;
+use std::sync::Arc;
+
 // Internal Dependencies ------------------------------------------------------
-use crate::{Month, WeekDay};
+use crate::{Month, ViewMode, WeekDay};
 
 /// Trait for localization of a [`CalendarView`](struct.CalendarView.html).
 pub trait Locale {
@@ -8,10 +12,316 @@ pub trait Locale {
     /// Both *short* e.g. `Th` and *long* translations e.g. `Thursday` are suppported.
     fn week_day(day: WeekDay, long_text: bool) -> &'static str;
 
+    /// Method returning the single character form of a specific
+    /// [`WeekDay`](enum.WeekDay.html), e.g. `T`.
+    ///
+    /// Defaults to the first character of the *short* translation.
+    fn week_day_narrow(day: WeekDay) -> &'static str {
+        first_char(Self::week_day(day, false))
+    }
+
     /// Method returning the localized string for a specific [`Month`](enum.Month.html).
     ///
     /// Both *short* e.g. `Dec` and *long* translations e.g. `December` are suppported.
+    ///
+    /// Long translations are used in the header of the month view, which
+    /// widens the [`CalendarView`](struct.CalendarView.html) if it would not
+    /// fit otherwise.
     fn month(month: Month, long_text: bool) -> &'static str;
+
+    /// Method returning the day on which weeks start in this locale.
+    ///
+    /// Used by [`CalendarView::with_locale_defaults`](struct.CalendarView.html#method.with_locale_defaults).
+    /// Defaults to `WeekDay::Monday`.
+    fn week_start() -> WeekDay {
+        WeekDay::Monday
+    }
+
+    /// Method returning the localized label for a specific year.
+    ///
+    /// Years before `1` are counted backwards, e.g. year `0` is `1 BC`.
+    /// Defaults to English.
+    fn era_label(year: i32) -> String {
+        english_era_label(year)
+    }
+
+    /// Method returning a localized description of a date which lies `days`
+    /// days after today, e.g. `Tomorrow` or `3 days ago`.
+    ///
+    /// Negative values describe dates in the past. Defaults to English.
+    fn relative_days(days: i64) -> String {
+        english_relative_days(days)
+    }
+
+    /// Method returning the localized ordinal for a day of the month, e.g.
+    /// `1st` or `22nd`.
+    ///
+    /// Defaults to English.
+    fn ordinal_day(day: u32) -> String {
+        english_ordinal_day(day)
+    }
+
+    /// Method returning a localized description of the position of a day
+    /// within its year, e.g. `Day 207 of 365, 158 remaining`.
+    ///
+    /// Defaults to English.
+    fn day_of_year_label(day: u32, days_in_year: u32) -> String {
+        english_day_of_year_label(day, days_in_year)
+    }
+
+    /// Method returning a localized long form of a date, e.g.
+    /// `March 14th 2024`.
+    ///
+    /// Defaults to the long month name, followed by the
+    /// [`ordinal_day`](#method.ordinal_day) and the
+    /// [`era_label`](#method.era_label).
+    fn date_label(month: Month, day: u32, year: i32) -> String {
+        format!(
+            "{} {} {}",
+            Self::month(month, true),
+            Self::ordinal_day(day),
+            Self::era_label(year)
+        )
+    }
+
+    /// Method returning the localized name of a [`ViewMode`](enum.ViewMode.html),
+    /// e.g. `Month view`.
+    ///
+    /// Defaults to English.
+    fn view_mode_label(mode: ViewMode) -> String {
+        english_view_mode_label(mode)
+    }
+
+    /// Method returning a localized description of the week starting on the
+    /// given, already localized date, e.g. `week of March 11th 2024`.
+    ///
+    /// Defaults to English.
+    fn week_of_label(date: &str) -> String {
+        english_week_of_label(date)
+    }
+
+    /// Method returning a localized description of the selected and the
+    /// cursor position, e.g. `14th selected, cursor on 20th`.
+    ///
+    /// Defaults to English.
+    fn selection_label(selected: &str, cursor: &str) -> String {
+        english_selection_label(selected, cursor)
+    }
+}
+
+/// Object safe variant of the [`Locale`](trait.Locale.html) trait, allowing
+/// the localization of a [`CalendarView`](struct.CalendarView.html) to be
+/// switched at runtime.
+///
+/// See [`Locale`](trait.Locale.html) for a description of the methods.
+pub trait RuntimeLocale: Send + Sync {
+    /// Method returning the localized string for a specific [`WeekDay`](enum.WeekDay.html).
+    fn week_day(&self, day: WeekDay, long_text: bool) -> &'static str;
+
+    /// Method returning the single character form of a specific
+    /// [`WeekDay`](enum.WeekDay.html).
+    fn week_day_narrow(&self, day: WeekDay) -> &'static str {
+        first_char(self.week_day(day, false))
+    }
+
+    /// Method returning the localized string for a specific [`Month`](enum.Month.html).
+    fn month(&self, month: Month, long_text: bool) -> &'static str;
+
+    /// Method returning the day on which weeks start in this locale.
+    fn week_start(&self) -> WeekDay {
+        WeekDay::Monday
+    }
+
+    /// Method returning the localized label for a specific year.
+    fn era_label(&self, year: i32) -> String {
+        english_era_label(year)
+    }
+
+    /// Method returning a localized description of a date which lies `days`
+    /// days after today.
+    fn relative_days(&self, days: i64) -> String {
+        english_relative_days(days)
+    }
+
+    /// Method returning the localized ordinal for a day of the month.
+    fn ordinal_day(&self, day: u32) -> String {
+        english_ordinal_day(day)
+    }
+
+    /// Method returning a localized description of the position of a day
+    /// within its year.
+    fn day_of_year_label(&self, day: u32, days_in_year: u32) -> String {
+        english_day_of_year_label(day, days_in_year)
+    }
+
+    /// Method returning a localized long form of a date.
+    fn date_label(&self, month: Month, day: u32, year: i32) -> String {
+        format!(
+            "{} {} {}",
+            self.month(month, true),
+            self.ordinal_day(day),
+            self.era_label(year)
+        )
+    }
+
+    /// Method returning the localized name of a [`ViewMode`](enum.ViewMode.html).
+    fn view_mode_label(&self, mode: ViewMode) -> String {
+        english_view_mode_label(mode)
+    }
+
+    /// Method returning a localized description of the week starting on the
+    /// given date.
+    fn week_of_label(&self, date: &str) -> String {
+        english_week_of_label(date)
+    }
+
+    /// Method returning a localized description of the selected and the
+    /// cursor position.
+    fn selection_label(&self, selected: &str, cursor: &str) -> String {
+        english_selection_label(selected, cursor)
+    }
+}
+
+/// Runtime selectable locale for a [`CalendarView`](struct.CalendarView.html).
+///
+/// Set via [`CalendarView::set_locale`](struct.CalendarView.html#method.set_locale).
+#[derive(Clone)]
+pub enum DynLocale {
+    /// The built-in English locale.
+    English,
+    /// A custom locale implementation.
+    Custom(Arc<dyn RuntimeLocale>),
+}
+
+impl From<Box<dyn RuntimeLocale>> for DynLocale {
+    fn from(locale: Box<dyn RuntimeLocale>) -> Self {
+        DynLocale::Custom(Arc::from(locale))
+    }
+}
+
+impl DynLocale {
+    /// Creates a `DynLocale` from a type implementing the
+    /// [`Locale`](trait.Locale.html) trait.
+    pub fn from_locale<L: Locale + 'static>() -> Self {
+        DynLocale::Custom(Arc::new(StaticLocale::<L>::new()))
+    }
+
+    fn inner(&self) -> &dyn RuntimeLocale {
+        match self {
+            DynLocale::English => &StaticLocale::<EnglishLocale>(PhantomData),
+            DynLocale::Custom(locale) => &**locale,
+        }
+    }
+}
+
+impl RuntimeLocale for DynLocale {
+    fn week_day(&self, day: WeekDay, long_text: bool) -> &'static str {
+        self.inner().week_day(day, long_text)
+    }
+
+    fn week_day_narrow(&self, day: WeekDay) -> &'static str {
+        self.inner().week_day_narrow(day)
+    }
+
+    fn month(&self, month: Month, long_text: bool) -> &'static str {
+        self.inner().month(month, long_text)
+    }
+
+    fn week_start(&self) -> WeekDay {
+        self.inner().week_start()
+    }
+
+    fn era_label(&self, year: i32) -> String {
+        self.inner().era_label(year)
+    }
+
+    fn relative_days(&self, days: i64) -> String {
+        self.inner().relative_days(days)
+    }
+
+    fn ordinal_day(&self, day: u32) -> String {
+        self.inner().ordinal_day(day)
+    }
+
+    fn day_of_year_label(&self, day: u32, days_in_year: u32) -> String {
+        self.inner().day_of_year_label(day, days_in_year)
+    }
+
+    fn date_label(&self, month: Month, day: u32, year: i32) -> String {
+        self.inner().date_label(month, day, year)
+    }
+
+    fn view_mode_label(&self, mode: ViewMode) -> String {
+        self.inner().view_mode_label(mode)
+    }
+
+    fn week_of_label(&self, date: &str) -> String {
+        self.inner().week_of_label(date)
+    }
+
+    fn selection_label(&self, selected: &str, cursor: &str) -> String {
+        self.inner().selection_label(selected, cursor)
+    }
+}
+
+/// Adapter exposing a [`Locale`](trait.Locale.html) type as a
+/// [`RuntimeLocale`](trait.RuntimeLocale.html).
+pub(crate) struct StaticLocale<L>(pub(crate) PhantomData<fn() -> L>);
+
+impl<L> StaticLocale<L> {
+    pub(crate) fn new() -> Self {
+        StaticLocale(PhantomData)
+    }
+}
+
+impl<L: Locale> RuntimeLocale for StaticLocale<L> {
+    fn week_day(&self, day: WeekDay, long_text: bool) -> &'static str {
+        L::week_day(day, long_text)
+    }
+
+    fn week_day_narrow(&self, day: WeekDay) -> &'static str {
+        L::week_day_narrow(day)
+    }
+
+    fn month(&self, month: Month, long_text: bool) -> &'static str {
+        L::month(month, long_text)
+    }
+
+    fn week_start(&self) -> WeekDay {
+        L::week_start()
+    }
+
+    fn era_label(&self, year: i32) -> String {
+        L::era_label(year)
+    }
+
+    fn relative_days(&self, days: i64) -> String {
+        L::relative_days(days)
+    }
+
+    fn ordinal_day(&self, day: u32) -> String {
+        L::ordinal_day(day)
+    }
+
+    fn day_of_year_label(&self, day: u32, days_in_year: u32) -> String {
+        L::day_of_year_label(day, days_in_year)
+    }
+
+    fn date_label(&self, month: Month, day: u32, year: i32) -> String {
+        L::date_label(month, day, year)
+    }
+
+    fn view_mode_label(&self, mode: ViewMode) -> String {
+        L::view_mode_label(mode)
+    }
+
+    fn week_of_label(&self, date: &str) -> String {
+        L::week_of_label(date)
+    }
+
+    fn selection_label(&self, selected: &str, cursor: &str) -> String {
+        L::selection_label(selected, cursor)
+    }
 }
 
 /// English locale for a [`CalendarView`](struct.CalendarView.html).
@@ -76,3 +386,383 @@ impl Locale for EnglishLocale {
         }
     }
 }
+
+/// German locale for a [`CalendarView`](struct.CalendarView.html).
+#[cfg(feature = "locale-de")]
+pub struct GermanLocale;
+
+#[cfg(feature = "locale-de")]
+impl Locale for GermanLocale {
+    fn week_day(day: WeekDay, long_text: bool) -> &'static str {
+        if long_text {
+            match day {
+                WeekDay::Monday => "Montag",
+                WeekDay::Tuesday => "Dienstag",
+                WeekDay::Wednesday => "Mittwoch",
+                WeekDay::Thursday => "Donnerstag",
+                WeekDay::Friday => "Freitag",
+                WeekDay::Saturday => "Samstag",
+                WeekDay::Sunday => "Sonntag",
+            }
+        } else {
+            match day {
+                WeekDay::Monday => "Mo",
+                WeekDay::Tuesday => "Di",
+                WeekDay::Wednesday => "Mi",
+                WeekDay::Thursday => "Do",
+                WeekDay::Friday => "Fr",
+                WeekDay::Saturday => "Sa",
+                WeekDay::Sunday => "So",
+            }
+        }
+    }
+
+    fn month(month: Month, long_text: bool) -> &'static str {
+        if long_text {
+            match month {
+                Month::January => "Januar",
+                Month::February => "Februar",
+                Month::March => "März",
+                Month::April => "April",
+                Month::May => "Mai",
+                Month::June => "Juni",
+                Month::July => "Juli",
+                Month::August => "August",
+                Month::September => "September",
+                Month::October => "Oktober",
+                Month::November => "November",
+                Month::December => "Dezember",
+            }
+        } else {
+            match month {
+                Month::January => "Jan",
+                Month::February => "Feb",
+                Month::March => "Mär",
+                Month::April => "Apr",
+                Month::May => "Mai",
+                Month::June => "Jun",
+                Month::July => "Jul",
+                Month::August => "Aug",
+                Month::September => "Sep",
+                Month::October => "Okt",
+                Month::November => "Nov",
+                Month::December => "Dez",
+            }
+        }
+    }
+
+    fn era_label(year: i32) -> String {
+        if year < 1 {
+            format!("{} v. Chr.", 1 - year)
+        } else {
+            year.to_string()
+        }
+    }
+
+    fn ordinal_day(day: u32) -> String {
+        format!("{}.", day)
+    }
+
+    fn date_label(month: Month, day: u32, year: i32) -> String {
+        format!(
+            "{} {} {}",
+            Self::ordinal_day(day),
+            Self::month(month, true),
+            Self::era_label(year)
+        )
+    }
+
+    fn view_mode_label(mode: ViewMode) -> String {
+        match mode {
+            ViewMode::Week => "Wochenansicht",
+            ViewMode::Month => "Monatsansicht",
+            ViewMode::Year => "Jahresansicht",
+            ViewMode::Decade => "Jahrzehntansicht",
+        }
+        .to_string()
+    }
+
+    fn week_of_label(date: &str) -> String {
+        format!("Woche vom {}", date)
+    }
+
+    fn selection_label(selected: &str, cursor: &str) -> String {
+        format!("{} ausgewählt, Cursor auf {}", selected, cursor)
+    }
+}
+
+/// French locale for a [`CalendarView`](struct.CalendarView.html).
+#[cfg(feature = "locale-fr")]
+pub struct FrenchLocale;
+
+#[cfg(feature = "locale-fr")]
+impl Locale for FrenchLocale {
+    fn week_day(day: WeekDay, long_text: bool) -> &'static str {
+        if long_text {
+            match day {
+                WeekDay::Monday => "lundi",
+                WeekDay::Tuesday => "mardi",
+                WeekDay::Wednesday => "mercredi",
+                WeekDay::Thursday => "jeudi",
+                WeekDay::Friday => "vendredi",
+                WeekDay::Saturday => "samedi",
+                WeekDay::Sunday => "dimanche",
+            }
+        } else {
+            match day {
+                WeekDay::Monday => "lu",
+                WeekDay::Tuesday => "ma",
+                WeekDay::Wednesday => "me",
+                WeekDay::Thursday => "je",
+                WeekDay::Friday => "ve",
+                WeekDay::Saturday => "sa",
+                WeekDay::Sunday => "di",
+            }
+        }
+    }
+
+    fn month(month: Month, long_text: bool) -> &'static str {
+        if long_text {
+            match month {
+                Month::January => "janvier",
+                Month::February => "février",
+                Month::March => "mars",
+                Month::April => "avril",
+                Month::May => "mai",
+                Month::June => "juin",
+                Month::July => "juillet",
+                Month::August => "août",
+                Month::September => "septembre",
+                Month::October => "octobre",
+                Month::November => "novembre",
+                Month::December => "décembre",
+            }
+        } else {
+            match month {
+                Month::January => "janv.",
+                Month::February => "févr.",
+                Month::March => "mars",
+                Month::April => "avr.",
+                Month::May => "mai",
+                Month::June => "juin",
+                Month::July => "juil.",
+                Month::August => "août",
+                Month::September => "sept.",
+                Month::October => "oct.",
+                Month::November => "nov.",
+                Month::December => "déc.",
+            }
+        }
+    }
+
+    fn era_label(year: i32) -> String {
+        if year < 1 {
+            format!("{} av. J.-C.", 1 - year)
+        } else {
+            year.to_string()
+        }
+    }
+
+    fn ordinal_day(day: u32) -> String {
+        if day == 1 {
+            "1er".to_string()
+        } else {
+            day.to_string()
+        }
+    }
+
+    fn date_label(month: Month, day: u32, year: i32) -> String {
+        format!(
+            "{} {} {}",
+            Self::ordinal_day(day),
+            Self::month(month, true),
+            Self::era_label(year)
+        )
+    }
+
+    fn view_mode_label(mode: ViewMode) -> String {
+        match mode {
+            ViewMode::Week => "Vue semaine",
+            ViewMode::Month => "Vue mois",
+            ViewMode::Year => "Vue année",
+            ViewMode::Decade => "Vue décennie",
+        }
+        .to_string()
+    }
+
+    fn week_of_label(date: &str) -> String {
+        format!("semaine du {}", date)
+    }
+
+    fn selection_label(selected: &str, cursor: &str) -> String {
+        format!("{} sélectionné, curseur sur {}", selected, cursor)
+    }
+}
+
+/// Spanish locale for a [`CalendarView`](struct.CalendarView.html).
+#[cfg(feature = "locale-es")]
+pub struct SpanishLocale;
+
+#[cfg(feature = "locale-es")]
+impl Locale for SpanishLocale {
+    fn week_day(day: WeekDay, long_text: bool) -> &'static str {
+        if long_text {
+            match day {
+                WeekDay::Monday => "lunes",
+                WeekDay::Tuesday => "martes",
+                WeekDay::Wednesday => "miércoles",
+                WeekDay::Thursday => "jueves",
+                WeekDay::Friday => "viernes",
+                WeekDay::Saturday => "sábado",
+                WeekDay::Sunday => "domingo",
+            }
+        } else {
+            match day {
+                WeekDay::Monday => "lu",
+                WeekDay::Tuesday => "ma",
+                WeekDay::Wednesday => "mi",
+                WeekDay::Thursday => "ju",
+                WeekDay::Friday => "vi",
+                WeekDay::Saturday => "sá",
+                WeekDay::Sunday => "do",
+            }
+        }
+    }
+
+    fn month(month: Month, long_text: bool) -> &'static str {
+        if long_text {
+            match month {
+                Month::January => "enero",
+                Month::February => "febrero",
+                Month::March => "marzo",
+                Month::April => "abril",
+                Month::May => "mayo",
+                Month::June => "junio",
+                Month::July => "julio",
+                Month::August => "agosto",
+                Month::September => "septiembre",
+                Month::October => "octubre",
+                Month::November => "noviembre",
+                Month::December => "diciembre",
+            }
+        } else {
+            match month {
+                Month::January => "ene",
+                Month::February => "feb",
+                Month::March => "mar",
+                Month::April => "abr",
+                Month::May => "may",
+                Month::June => "jun",
+                Month::July => "jul",
+                Month::August => "ago",
+                Month::September => "sep",
+                Month::October => "oct",
+                Month::November => "nov",
+                Month::December => "dic",
+            }
+        }
+    }
+
+    fn era_label(year: i32) -> String {
+        if year < 1 {
+            format!("{} a. C.", 1 - year)
+        } else {
+            year.to_string()
+        }
+    }
+
+    fn ordinal_day(day: u32) -> String {
+        day.to_string()
+    }
+
+    fn date_label(month: Month, day: u32, year: i32) -> String {
+        format!(
+            "{} de {} de {}",
+            Self::ordinal_day(day),
+            Self::month(month, true),
+            Self::era_label(year)
+        )
+    }
+
+    fn view_mode_label(mode: ViewMode) -> String {
+        match mode {
+            ViewMode::Week => "Vista semanal",
+            ViewMode::Month => "Vista mensual",
+            ViewMode::Year => "Vista anual",
+            ViewMode::Decade => "Vista de década",
+        }
+        .to_string()
+    }
+
+    fn week_of_label(date: &str) -> String {
+        format!("semana del {}", date)
+    }
+
+    fn selection_label(selected: &str, cursor: &str) -> String {
+        format!("{} seleccionado, cursor en {}", selected, cursor)
+    }
+}
+
+// Helpers --------------------------------------------------------------------
+fn first_char(text: &'static str) -> &'static str {
+    match text.chars().next() {
+        Some(c) => &text[..c.len_utf8()],
+        None => text,
+    }
+}
+
+fn english_era_label(year: i32) -> String {
+    if year < 1 {
+        format!("{} BC", 1 - year)
+    } else {
+        year.to_string()
+    }
+}
+
+fn english_relative_days(days: i64) -> String {
+    match days {
+        0 => "Today".to_string(),
+        1 => "Tomorrow".to_string(),
+        -1 => "Yesterday".to_string(),
+        days if days > 0 => format!("In {} days", days),
+        days => format!("{} days ago", -days),
+    }
+}
+
+fn english_ordinal_day(day: u32) -> String {
+    let suffix = match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", day, suffix)
+}
+
+fn english_view_mode_label(mode: ViewMode) -> String {
+    match mode {
+        ViewMode::Week => "Week view",
+        ViewMode::Month => "Month view",
+        ViewMode::Year => "Year view",
+        ViewMode::Decade => "Decade view",
+    }
+    .to_string()
+}
+
+fn english_week_of_label(date: &str) -> String {
+    format!("week of {}", date)
+}
+
+fn english_selection_label(selected: &str, cursor: &str) -> String {
+    format!("{} selected, cursor on {}", selected, cursor)
+}
+
+fn english_day_of_year_label(day: u32, days_in_year: u32) -> String {
+    format!(
+        "Day {} of {}, {} remaining",
+        day,
+        days_in_year,
+        days_in_year.saturating_sub(day)
+    )
+}
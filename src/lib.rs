@@ -14,34 +14,65 @@ extern crate cursive_core as cursive;
 
 // STD Dependencies -----------------------------------------------------------
 use std::cmp;
+use std::collections::HashSet;
 use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Bound, RangeBounds};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 // External Dependencies ------------------------------------------------------
 use chrono::offset::TimeZone;
 use chrono::prelude::*;
 
+use crate::cursive::align::{HAlign, VAlign};
 use crate::cursive::direction::Direction;
-use crate::cursive::event::{Callback, Event, EventResult, Key, MouseButton, MouseEvent};
-use crate::cursive::theme::ColorStyle;
+use crate::cursive::event::{Callback, Event, EventResult, MouseButton, MouseEvent};
+use crate::cursive::theme::{Color, ColorStyle, Effect};
 use crate::cursive::vec::Vec2;
 use crate::cursive::view::{CannotFocus, View};
 use crate::cursive::With;
 use crate::cursive::{Cursive, Printer};
 
+#[cfg(test)]
+use crate::cursive::event::Key;
+
 // Modules --------------------------------------------------------------------
+mod builder;
+mod date_time;
+mod key_bindings;
 mod l16n;
 mod month;
+mod naive;
+mod state;
+mod style;
 mod week_day;
 
 // Re-Exports -----------------------------------------------------------------
-pub use crate::l16n::{EnglishLocale, Locale};
+pub use crate::builder::CalendarViewBuilder;
+pub use crate::date_time::DateTimeCalendarView;
+pub use crate::key_bindings::{KeyAction, KeyBindings};
+#[cfg(feature = "locale-fr")]
+pub use crate::l16n::FrenchLocale;
+#[cfg(feature = "locale-de")]
+pub use crate::l16n::GermanLocale;
+#[cfg(feature = "locale-es")]
+pub use crate::l16n::SpanishLocale;
+pub use crate::l16n::{DynLocale, EnglishLocale, Locale, RuntimeLocale};
 pub use crate::month::Month;
+pub use crate::naive::NaiveCalendarView;
+pub use crate::state::{CalendarState, PersistedState};
+pub use crate::style::CalendarStyle;
 pub use crate::week_day::WeekDay;
 
+use crate::l16n::StaticLocale;
+
 /// Enumeration of all view modes supported by a [`CalendarView`](struct.CalendarView.html).
 #[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ViewMode {
+    /// View of a specific month, allowing selection of entire weeks.
+    Week,
     /// View of a specific month, allowing selection of individual days.
     Month,
     /// View of a specific year, allowing selection of individual months.
@@ -50,19 +81,140 @@ pub enum ViewMode {
     Decade,
 }
 
+/// Enumeration of the limits navigation of a [`CalendarView`](struct.CalendarView.html)
+/// can run into.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Boundary {
+    /// The earliest selectable date.
+    Earliest,
+    /// The latest selectable date.
+    Latest,
+}
+
+/// Enumeration of the ways arrow key navigation of a
+/// [`CalendarView`](struct.CalendarView.html) behaves at the earliest and
+/// latest selectable dates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NavMode {
+    /// Keep the cursor on the earliest or latest date.
+    Clamp,
+    /// Move the cursor to the opposite end of the allowed range.
+    Wrap,
+}
+
+/// Enumeration of the week numbering schemes shown next to the rows of the
+/// `ViewMode::Month` grid of a [`CalendarView`](struct.CalendarView.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WeekNumbering {
+    /// No week numbers are shown.
+    None,
+    /// ISO 8601 week numbers. Rows which do not start on a Monday show the
+    /// ISO week of their Thursday, i.e. of the majority of their days.
+    Iso,
+    /// Week of the year with week `1` being the week which contains
+    /// January 1st, e.g. as used in the United States.
+    UsWeekOfYear,
+}
+
 /// A callback taking a date as parameter.
 ///
 /// This is an internal type used to improve readability.
 type DateCallback<T> = Arc<dyn Fn(&mut Cursive, &Date<T>) + Send + Sync>;
 
+/// A callback taking a state snapshot as parameter.
+///
+/// This is an internal type used to improve readability.
+type StateCallback<T> = Arc<dyn Fn(&mut Cursive, &CalendarState<T>) + Send + Sync>;
+
+/// A callback taking a view mode as parameter.
+///
+/// This is an internal type used to improve readability.
+type ViewModeCallback = Arc<dyn Fn(&mut Cursive, ViewMode) + Send + Sync>;
+
+/// A callback taking a boundary as parameter.
+///
+/// This is an internal type used to improve readability.
+type BoundaryCallback = Arc<dyn Fn(&mut Cursive, Boundary) + Send + Sync>;
+
+/// A callback returning an optional header title for a view mode.
+///
+/// This is an internal type used to improve readability.
+type HeaderCallback<T> = Arc<dyn Fn(ViewMode, &Date<T>) -> Option<String> + Send + Sync>;
+
+/// A callback taking a list of dates as parameter.
+///
+/// This is an internal type used to improve readability.
+type DatesCallback<T> = Arc<dyn Fn(&mut Cursive, &[Date<T>]) + Send + Sync>;
+
+/// A callback taking a date range as parameter.
+///
+/// This is an internal type used to improve readability.
+type RangeCallback<T> = Arc<dyn Fn(&mut Cursive, &Date<T>, &Date<T>) + Send + Sync>;
+
+/// A callback returning an optional color for a date.
+///
+/// This is an internal type used to improve readability.
+type DateStyleCallback<T> = Arc<dyn Fn(&Date<T>) -> Option<ColorStyle> + Send + Sync>;
+
+/// A callback describing why a date cannot be selected.
+///
+/// This is an internal type used to improve readability.
+type DateReasonCallback<T> = Arc<dyn Fn(&Date<T>) -> Option<String> + Send + Sync>;
+
+/// A callback returning the label of a date.
+///
+/// This is an internal type used to improve readability.
+type DateLabelCallback<T> = Arc<dyn Fn(&Date<T>) -> String + Send + Sync>;
+
+/// A callback returning an intensity for a date.
+///
+/// This is an internal type used to improve readability.
+type DateValueCallback<T> = Arc<dyn Fn(&Date<T>) -> u8 + Send + Sync>;
+
+/// A predicate on a date.
+///
+/// This is an internal type used to improve readability.
+type DatePredicate<T> = Arc<dyn Fn(&Date<T>) -> bool + Send + Sync>;
+
+/// Maximum delay between two presses of `<Enter>` to count as a double press.
+const DOUBLE_ENTER_TIMEOUT: Duration = Duration::from_millis(400);
+
+/// Maximum delay between two typed characters to extend a type-ahead search.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Maximum number of days scanned when searching for a selectable date.
+const SEARCH_WINDOW_DAYS: usize = 3660;
+
+/// Maximum number of months scanned when paging to a month with selectable
+/// days.
+const SEARCH_WINDOW_MONTHS: i32 = 120;
+
+/// Position, width and whether a grid cell holds the cursor and the selected
+/// date, as used for drawing the monochrome markers.
+type MarkerCell = ((i32, i32), i32, bool, bool);
+
 /// View for selecting a date, supporting different modes for day, month or
 /// year based selection.
 ///
-/// View modes can be navigated via `Backspace` and `Enter`.
+/// View modes can be navigated via `Backspace` and `Enter`, `t` moves the
+/// cursor back to today.
 ///
 /// Custom localization is possible by providing an implementation of the
 /// [`Locale`](trait.Locale.html) trait.
 ///
+/// All setters take effect on the next draw. Since cursive only redraws in
+/// response to events, changes made from outside the event loop (e.g. after
+/// data was loaded asynchronously) should be applied through
+/// `Cursive::cb_sink()`, which makes cursive redraw once the callback ran:
+///
+/// ```ignore
+/// siv.cb_sink().send(Box::new(|s| {
+///     s.call_on_name("calendar", |view: &mut CalendarView<Utc, EnglishLocale>| {
+///         view.invalidate();
+///     });
+/// }))?;
+/// ```
+///
 /// # Examples
 ///
 /// ```
@@ -84,49 +236,335 @@ type DateCallback<T> = Arc<dyn Fn(&mut Cursive, &Date<T>) + Send + Sync>;
 pub struct CalendarView<T: TimeZone, L: Locale> {
     enabled: bool,
     show_iso_weeks: bool,
+    week_numbering: WeekNumbering,
+    reserve_iso_column: bool,
+    week_number_base: Option<(Date<T>, i32)>,
+    monochrome_markers: bool,
+    skip_disabled_on_nav: bool,
+    navigation_mode: NavMode,
+    business_days_only: bool,
+    show_adjacent_month_names: bool,
+    fast_drill: bool,
+    enter_commits_immediately: bool,
+    multi_select: bool,
+    range_select: bool,
+    year_locked: bool,
+    sticky_day_of_month: bool,
+    click_selects_any: bool,
+    click_adjacent_centers: bool,
+    emphasize_available: bool,
+    show_day_of_year: bool,
+    highlight_today_week: bool,
+    show_disabled_reason: bool,
+    show_day_of_year_footer: bool,
+    fixed_height: bool,
+    anchor_week: Option<Date<T>>,
+    anchor_row: usize,
+    decade_size: u32,
+    rtl: bool,
+    show_adjacent_days: bool,
+    cell_width: usize,
+    weekday_header_width: usize,
+    v_align: VAlign,
+    center_in_parent: bool,
     week_start: WeekDay,
+    week_start_explicit: bool,
+    disabled_week_days: Vec<WeekDay>,
+    style: CalendarStyle,
+    heatmap_palette: Vec<Color>,
 
     highest_view_mode: ViewMode,
     lowest_view_mode: ViewMode,
 
     view_mode: ViewMode,
     view_date: Date<T>,
+    sticky_day: Option<u32>,
+    last_drill: Option<(Instant, ViewMode, Date<T>)>,
+    type_ahead: Option<(Instant, String)>,
+    today_key: Option<Event>,
+    submitted: bool,
+    key_bindings: KeyBindings,
 
+    today: Date<T>,
     earliest_date: Option<Date<T>>,
     latest_date: Option<Date<T>>,
     date: Date<T>,
+    selected_dates: HashSet<Date<T>>,
+    range_anchor: Option<Date<T>>,
+    selected_range: Option<(Date<T>, Date<T>)>,
     on_submit: Option<DateCallback<T>>,
     on_select: Option<DateCallback<T>>,
+    on_change: Option<StateCallback<T>>,
+    on_view_mode_change: Option<ViewModeCallback>,
+    on_multi_change: Option<DatesCallback<T>>,
+    on_range_submit: Option<RangeCallback<T>>,
+    on_mode_locked: Option<Callback>,
+    on_focus: Option<Callback>,
+    on_blur: Option<Callback>,
+    on_boundary: Option<BoundaryCallback>,
+    disabled_reason: Option<DateReasonCallback<T>>,
+    day_label: Option<DateLabelCallback<T>>,
+    header_formatter: Option<HeaderCallback<T>>,
+    day_decorator: Option<DateStyleCallback<T>>,
+    heatmap: Option<DateValueCallback<T>>,
+    disabled_dates: Option<DatePredicate<T>>,
+    blackout_dates: HashSet<Date<T>>,
+    event_dates: HashSet<Date<T>>,
+    event_marker: char,
+    silent: bool,
 
     size: Vec2,
+    layout_size: Vec2,
+    invalidated: bool,
 
+    locale: Option<DynLocale>,
     _localization: PhantomData<L>,
 }
 
+impl<T: TimeZone, L: Locale> Clone for CalendarView<T, L> {
+    fn clone(&self) -> Self {
+        Self {
+            enabled: self.enabled,
+            show_iso_weeks: self.show_iso_weeks,
+            week_numbering: self.week_numbering,
+            reserve_iso_column: self.reserve_iso_column,
+            week_number_base: self.week_number_base.clone(),
+            monochrome_markers: self.monochrome_markers,
+            skip_disabled_on_nav: self.skip_disabled_on_nav,
+            navigation_mode: self.navigation_mode,
+            business_days_only: self.business_days_only,
+            show_adjacent_month_names: self.show_adjacent_month_names,
+            fast_drill: self.fast_drill,
+            enter_commits_immediately: self.enter_commits_immediately,
+            multi_select: self.multi_select,
+            range_select: self.range_select,
+            year_locked: self.year_locked,
+            sticky_day_of_month: self.sticky_day_of_month,
+            click_selects_any: self.click_selects_any,
+            click_adjacent_centers: self.click_adjacent_centers,
+            emphasize_available: self.emphasize_available,
+            show_day_of_year: self.show_day_of_year,
+            highlight_today_week: self.highlight_today_week,
+            show_disabled_reason: self.show_disabled_reason,
+            show_day_of_year_footer: self.show_day_of_year_footer,
+            fixed_height: self.fixed_height,
+            anchor_week: self.anchor_week.clone(),
+            anchor_row: self.anchor_row,
+            decade_size: self.decade_size,
+            rtl: self.rtl,
+            show_adjacent_days: self.show_adjacent_days,
+            cell_width: self.cell_width,
+            weekday_header_width: self.weekday_header_width,
+            v_align: self.v_align,
+            center_in_parent: self.center_in_parent,
+            week_start: self.week_start,
+            week_start_explicit: self.week_start_explicit,
+            disabled_week_days: self.disabled_week_days.clone(),
+            style: self.style,
+            heatmap_palette: self.heatmap_palette.clone(),
+            highest_view_mode: self.highest_view_mode,
+            lowest_view_mode: self.lowest_view_mode,
+            view_mode: self.view_mode,
+            view_date: self.view_date.clone(),
+            sticky_day: self.sticky_day,
+            last_drill: self.last_drill.clone(),
+            type_ahead: self.type_ahead.clone(),
+            today_key: self.today_key.clone(),
+            submitted: self.submitted,
+            key_bindings: self.key_bindings.clone(),
+            today: self.today.clone(),
+            earliest_date: self.earliest_date.clone(),
+            latest_date: self.latest_date.clone(),
+            date: self.date.clone(),
+            selected_dates: self.selected_dates.clone(),
+            range_anchor: self.range_anchor.clone(),
+            selected_range: self.selected_range.clone(),
+            on_submit: self.on_submit.clone(),
+            on_select: self.on_select.clone(),
+            on_change: self.on_change.clone(),
+            on_view_mode_change: self.on_view_mode_change.clone(),
+            on_multi_change: self.on_multi_change.clone(),
+            on_range_submit: self.on_range_submit.clone(),
+            on_mode_locked: self.on_mode_locked.clone(),
+            on_focus: self.on_focus.clone(),
+            on_blur: self.on_blur.clone(),
+            on_boundary: self.on_boundary.clone(),
+            disabled_reason: self.disabled_reason.clone(),
+            day_label: self.day_label.clone(),
+            header_formatter: self.header_formatter.clone(),
+            day_decorator: self.day_decorator.clone(),
+            heatmap: self.heatmap.clone(),
+            disabled_dates: self.disabled_dates.clone(),
+            blackout_dates: self.blackout_dates.clone(),
+            event_dates: self.event_dates.clone(),
+            event_marker: self.event_marker,
+            silent: self.silent,
+            size: self.size,
+            layout_size: self.layout_size,
+            invalidated: self.invalidated,
+            locale: self.locale.clone(),
+            _localization: PhantomData,
+        }
+    }
+}
+
 impl<T: TimeZone + Send + Sync, L: Locale + Send + Sync + 'static> CalendarView<T, L>
 where
     T::Offset: Send + Sync,
 {
     /// Creates new `CalendarView`.
+    ///
+    /// The given `today` is stored and drives all behavior which depends on
+    /// the current date, the view never queries the system clock itself.
     pub fn new(today: Date<T>) -> Self {
         Self {
             enabled: true,
             highest_view_mode: ViewMode::Decade,
             lowest_view_mode: ViewMode::Month,
             show_iso_weeks: false,
+            week_numbering: WeekNumbering::None,
+            reserve_iso_column: false,
+            week_number_base: None,
+            monochrome_markers: false,
+            skip_disabled_on_nav: false,
+            navigation_mode: NavMode::Clamp,
+            business_days_only: false,
+            show_adjacent_month_names: false,
+            fast_drill: false,
+            enter_commits_immediately: false,
+            multi_select: false,
+            range_select: false,
+            year_locked: false,
+            sticky_day_of_month: false,
+            click_selects_any: false,
+            click_adjacent_centers: false,
+            emphasize_available: false,
+            show_day_of_year: false,
+            highlight_today_week: false,
+            show_disabled_reason: false,
+            show_day_of_year_footer: false,
+            fixed_height: true,
+            anchor_week: None,
+            anchor_row: 0,
+            decade_size: 10,
+            rtl: false,
+            show_adjacent_days: true,
+            cell_width: 0,
+            weekday_header_width: 2,
+            v_align: VAlign::Top,
+            center_in_parent: false,
             week_start: WeekDay::Monday,
+            week_start_explicit: false,
+            disabled_week_days: Vec::new(),
+            style: CalendarStyle::default(),
+            heatmap_palette: vec![
+                Color::Rgb(14, 68, 41),
+                Color::Rgb(0, 109, 50),
+                Color::Rgb(38, 166, 65),
+                Color::Rgb(57, 211, 83),
+            ],
             date: today.clone(),
+            selected_dates: HashSet::new(),
+            range_anchor: None,
+            selected_range: None,
             earliest_date: None,
             latest_date: None,
             view_mode: ViewMode::Month,
-            view_date: today,
+            view_date: today.clone(),
+            sticky_day: None,
+            last_drill: None,
+            type_ahead: None,
+            today_key: Some(Event::Char('t')),
+            submitted: false,
+            key_bindings: KeyBindings::default(),
+            today,
             size: (0, 0).into(),
+            layout_size: (0, 0).into(),
+            invalidated: true,
             on_submit: None,
             on_select: None,
+            on_change: None,
+            on_view_mode_change: None,
+            on_multi_change: None,
+            on_range_submit: None,
+            on_mode_locked: None,
+            on_focus: None,
+            on_blur: None,
+            on_boundary: None,
+            disabled_reason: None,
+            day_label: None,
+            header_formatter: None,
+            day_decorator: None,
+            heatmap: None,
+            disabled_dates: None,
+            blackout_dates: HashSet::new(),
+            event_dates: HashSet::new(),
+            event_marker: '•',
+            silent: false,
+            locale: None,
             _localization: PhantomData,
         }
     }
 
+    /// Creates new `CalendarView` with the cursor placed on `view` while
+    /// `selected` is selected, e.g. to show the current month while a date
+    /// far in the past is selected.
+    ///
+    /// `today` is tracked the same way as with [`new`](#method.new).
+    pub fn with_view_date(today: Date<T>, selected: Date<T>, view: Date<T>) -> Self {
+        Self::new(today).with(|v| {
+            v.set_selected_date(selected);
+            v.set_view_date(view);
+        })
+    }
+
+    /// Creates a [`CalendarViewBuilder`](struct.CalendarViewBuilder.html),
+    /// collecting options for constructing several identically configured
+    /// views.
+    pub fn builder() -> CalendarViewBuilder<T, L>
+    where
+        T: 'static,
+    {
+        CalendarViewBuilder::new()
+    }
+
+    /// Creates new `CalendarView` for editing an existing date `value`.
+    ///
+    /// Both the selected and the visually selected date are set to `value`
+    /// and the view always opens in the `ViewMode::Month` view mode, so the
+    /// days around `value` are immediately visible. `today` is tracked the
+    /// same way as with [`new`](#method.new).
+    pub fn editing(today: Date<T>, value: Date<T>) -> Self {
+        Self::new(today).with(|v| {
+            v.set_selected_date(value.clone());
+            v.set_view_date(value);
+            v.set_view_mode(ViewMode::Month);
+        })
+    }
+
+    /// Creates new `CalendarView` using the defaults of its
+    /// [`Locale`](trait.Locale.html), e.g. the day on which weeks start.
+    pub fn with_locale_defaults(today: Date<T>) -> Self {
+        Self::new(today).with(|v| v.apply_locale_defaults())
+    }
+
+    /// Creates new `CalendarView` which only allows selection of days within
+    /// the month of `today`.
+    pub fn current_month_only(today: Date<T>) -> Self {
+        let month: Month = today.month0().into();
+        let last_day = month.number_of_days(today.year()) - 1;
+        let earliest = today.with_day0(0);
+        let latest = today.with_day0(last_day as u32);
+
+        let mut view = Self::new(today);
+        view.highest_view_mode = ViewMode::Month;
+        view.lowest_view_mode = ViewMode::Month;
+        view.view_mode = ViewMode::Month;
+        view.set_earliest_date(earliest);
+        view.set_latest_date(latest);
+        view
+    }
+
     /// Disables this view.
     ///
     /// A disabled view cannot be selected.
@@ -149,11 +587,371 @@ where
         self.enabled
     }
 
+    /// Returns the colors used to draw this view.
+    pub fn get_style(&self) -> CalendarStyle {
+        self.style
+    }
+
+    /// Sets the colors used to draw this view.
+    pub fn set_style(&mut self, style: CalendarStyle) {
+        self.style = style;
+    }
+
+    /// Sets the colors used to draw this view.
+    ///
+    /// Chainable variant.
+    pub fn style(self, style: CalendarStyle) -> Self {
+        self.with(|v| v.set_style(style))
+    }
+
+    /// Sets the color used for all cells while this view is disabled.
+    ///
+    /// Unavailable dates and the selected date keep their own colors so the
+    /// selection stays visible. Defaults to `ColorStyle::secondary()`.
+    pub fn set_disabled_style(&mut self, style: ColorStyle) {
+        self.style.disabled = style;
+    }
+
+    /// Sets the color used for all cells while this view is disabled.
+    ///
+    /// Chainable variant.
+    pub fn disabled_style(self, style: ColorStyle) -> Self {
+        self.with(|v| v.set_disabled_style(style))
+    }
+
+    /// Sets the color used for today's date in the `ViewMode::Month` view
+    /// mode when it is neither under the cursor nor selected.
+    ///
+    /// Today's date is additionally underlined, regardless of focus.
+    /// Defaults to `ColorStyle::title_primary()`.
+    pub fn set_today_style(&mut self, style: ColorStyle) {
+        self.style.today = style;
+    }
+
+    /// Sets the color used for today's date in the `ViewMode::Month` view
+    /// mode when it is neither under the cursor nor selected.
+    ///
+    /// Chainable variant.
+    pub fn today_style(self, style: ColorStyle) -> Self {
+        self.with(|v| v.set_today_style(style))
+    }
+
+    /// Sets the color used for the days between the start and end of the
+    /// range in range select mode.
+    ///
+    /// Defaults to `ColorStyle::highlight_inactive()`.
+    pub fn set_range_style(&mut self, style: ColorStyle) {
+        self.style.range = style;
+    }
+
+    /// Sets the color used for the days between the start and end of the
+    /// range in range select mode.
+    ///
+    /// Chainable variant.
+    pub fn range_style(self, style: ColorStyle) -> Self {
+        self.with(|v| v.set_range_style(style))
+    }
+
+    /// Sets the color used for the start and end day of the range in range
+    /// select mode.
+    ///
+    /// Defaults to `ColorStyle::highlight()`.
+    pub fn set_range_endpoint_style(&mut self, style: ColorStyle) {
+        self.style.range_endpoint = style;
+    }
+
+    /// Sets the color used for the start and end day of the range in range
+    /// select mode.
+    ///
+    /// Chainable variant.
+    pub fn range_endpoint_style(self, style: ColorStyle) -> Self {
+        self.with(|v| v.set_range_endpoint_style(style))
+    }
+
+    /// Marks this view as requiring a new layout and redraw.
+    ///
+    /// Use this when data consulted while drawing changed outside of this
+    /// view.
+    pub fn invalidate(&mut self) {
+        self.invalidated = true;
+    }
+
+    /// Returns the date this view considers to be today.
+    pub fn today(&self) -> Date<T> {
+        self.today.clone()
+    }
+
+    /// Sets the date this view considers to be today, e.g. to refresh it in
+    /// applications running past midnight.
+    ///
+    /// This does not change the selected or visually selected date.
+    pub fn set_today(&mut self, date: Date<T>) {
+        self.today = date;
+    }
+
+    /// Sets the event which moves the cursor back to today, clamped to the
+    /// earliest and latest selectable dates.
+    ///
+    /// In the `ViewMode::Month` and `ViewMode::Week` view modes the cursor
+    /// moves on to the nearest business day or enabled week day, as it does
+    /// when navigating with the arrow keys.
+    ///
+    /// Key bindings for the same event take precedence. Pass `None` to
+    /// disable the shortcut. Defaults to `t`.
+    pub fn set_today_key(&mut self, event: Option<Event>) {
+        self.today_key = event;
+    }
+
+    /// Sets the event which moves the cursor back to today.
+    ///
+    /// Chainable variant.
+    pub fn today_key(self, event: Option<Event>) -> Self {
+        self.with(|v| v.set_today_key(event))
+    }
+
+    /// Sets the events used for keyboard navigation.
+    ///
+    /// Defaults to the arrow keys, `<PageUp>`, `<PageDown>`, `<Backspace>`
+    /// and `<Enter>`.
+    pub fn set_key_bindings(&mut self, bindings: KeyBindings) {
+        self.key_bindings = bindings;
+    }
+
+    /// Sets the events used for keyboard navigation.
+    ///
+    /// Chainable variant.
+    pub fn key_bindings(self, bindings: KeyBindings) -> Self {
+        self.with(|v| v.set_key_bindings(bindings))
+    }
+
+    /// Returns a localized description of the given date relative to today,
+    /// e.g. `Tomorrow` or `In 3 days`.
+    pub fn relative_to_today(&self, date: &Date<T>) -> String {
+        self.runtime_locale().relative_days(
+            date.clone()
+                .signed_duration_since(self.today.clone())
+                .num_days(),
+        )
+    }
+
     /// Returns the currently selected date of this view.
     pub fn date(&self) -> Date<T> {
         self.date.clone()
     }
 
+    /// Returns all dates selected in multi select mode, in ascending order.
+    ///
+    /// See [`set_multi_select`](#method.set_multi_select).
+    pub fn selected_dates(&self) -> Vec<Date<T>> {
+        let mut dates: Vec<Date<T>> = self.selected_dates.iter().cloned().collect();
+        dates.sort();
+        dates
+    }
+
+    /// Returns the inclusive range of dates selected in range select mode,
+    /// with the start never lying after the end.
+    ///
+    /// See [`set_range_select`](#method.set_range_select).
+    pub fn selected_range(&self) -> Option<(Date<T>, Date<T>)> {
+        self.selected_range.clone()
+    }
+
+    /// Returns the visually selected date of this view.
+    pub fn get_view_date(&self) -> Date<T> {
+        self.view_date.clone()
+    }
+
+    /// Returns the currently active view mode of this view.
+    pub fn get_view_mode(&self) -> ViewMode {
+        self.view_mode
+    }
+
+    /// Returns the day on which weeks start in this view.
+    pub fn get_week_start(&self) -> WeekDay {
+        self.week_start
+    }
+
+    /// Returns whether ISO week numbers are shown in the `ViewMode::Month`
+    /// view mode.
+    pub fn shows_iso_weeks(&self) -> bool {
+        self.show_iso_weeks
+    }
+
+    /// Returns the week numbering scheme shown in the `ViewMode::Month` view
+    /// mode.
+    pub fn get_week_numbering(&self) -> WeekNumbering {
+        self.week_numbering
+    }
+
+    /// Returns the earliest date selectable by this view, if limited.
+    pub fn get_earliest_date(&self) -> Option<Date<T>> {
+        self.earliest_date.clone()
+    }
+
+    /// Returns the latest date selectable by this view, if limited.
+    pub fn get_latest_date(&self) -> Option<Date<T>> {
+        self.latest_date.clone()
+    }
+
+    /// Returns the Unix timestamp in seconds of the start of the currently
+    /// selected date, in the view's time zone.
+    ///
+    /// The start of the day is midnight, or the first valid local time if
+    /// midnight falls into a daylight saving time gap. Returns `None` if the
+    /// day has no valid local time at all.
+    pub fn selected_timestamp(&self) -> Option<i64> {
+        self.selected_day_start().map(|start| start.timestamp())
+    }
+
+    /// Returns the Unix timestamp in milliseconds of the start of the
+    /// currently selected date, in the view's time zone.
+    ///
+    /// See [`selected_timestamp`](#method.selected_timestamp).
+    pub fn selected_timestamp_millis(&self) -> Option<i64> {
+        self.selected_day_start()
+            .map(|start| start.timestamp_millis())
+    }
+
+    /// Returns the Julian Day Number of the currently selected date.
+    pub fn selected_julian_day(&self) -> i64 {
+        // January 1st of 1 CE is Julian Day 1721426
+        i64::from(self.date.num_days_from_ce()) + 1_721_425
+    }
+
+    /// Returns the ISO year and ISO week number of the currently selected
+    /// date as `(iso_year, week)`.
+    ///
+    /// The ISO year differs from the calendar year for days at the very
+    /// start or end of a year, e.g. January 1st 2017 lies in week 52 of 2016.
+    pub fn selected_iso_week(&self) -> (i32, u32) {
+        let week = self.date.iso_week();
+        (week.year(), week.week())
+    }
+
+    /// Returns the day of the week of the currently selected date.
+    pub fn selected_weekday(&self) -> WeekDay {
+        WeekDay::from(self.date.weekday().num_days_from_monday() as i32)
+    }
+
+    /// Returns the localized name of the month of the currently selected date.
+    pub fn selected_month_name(&self, long_text: bool) -> &'static str {
+        self.runtime_locale()
+            .month(self.date.month0().into(), long_text)
+    }
+
+    /// Returns the localized name of the month of the visually selected date.
+    pub fn view_month_name(&self, long_text: bool) -> &'static str {
+        self.runtime_locale()
+            .month(self.view_date.month0().into(), long_text)
+    }
+
+    /// Returns a description of the current view mode, cursor and selection,
+    /// e.g. `Month view, March 2024, 14th selected, cursor on 20th`.
+    ///
+    /// Month names, years and day ordinals are localized. This is intended to
+    /// be passed on to accessibility layers.
+    pub fn accessibility_label(&self) -> String {
+        let locale = self.runtime_locale();
+        let describe =
+            |date: &Date<T>| locale.date_label(date.month0().into(), date.day(), date.year());
+
+        let (title, selected, cursor) = match self.view_mode {
+            ViewMode::Week => (
+                self.month_title(),
+                locale.week_of_label(&describe(
+                    &self
+                        .week_start_of(&self.date)
+                        .unwrap_or_else(|| self.date.clone()),
+                )),
+                locale.week_of_label(&describe(
+                    &self
+                        .week_start_of(&self.view_date)
+                        .unwrap_or_else(|| self.view_date.clone()),
+                )),
+            ),
+            ViewMode::Month => (
+                self.month_title(),
+                if self.is_current_month(&self.date) {
+                    locale.ordinal_day(self.date.day())
+                } else {
+                    describe(&self.date)
+                },
+                locale.ordinal_day(self.view_date.day()),
+            ),
+            ViewMode::Year => (
+                locale.era_label(self.view_date.year()),
+                describe(&self.date),
+                locale
+                    .month(self.view_date.month0().into(), true)
+                    .to_string(),
+            ),
+            ViewMode::Decade => (
+                format!(
+                    "{} - {}",
+                    locale.era_label(self.decade_start()),
+                    locale.era_label(self.decade_start() + self.decade_size as i32 - 1)
+                ),
+                describe(&self.date),
+                locale.era_label(self.view_date.year()),
+            ),
+        };
+
+        format!(
+            "{}, {}, {}",
+            locale.view_mode_label(self.view_mode),
+            title,
+            locale.selection_label(&selected, &cursor)
+        )
+    }
+
+    /// Returns a snapshot of the observable state of this view.
+    ///
+    /// Use [`persisted_state`](#method.persisted_state) for a snapshot which
+    /// can be serialized and restored.
+    pub fn state(&self) -> CalendarState<T> {
+        CalendarState {
+            date: self.date.clone(),
+            view_date: self.view_date.clone(),
+            view_mode: self.view_mode,
+        }
+    }
+
+    /// Returns a time zone independent snapshot of the selection and
+    /// configuration of this view, which can be restored via
+    /// [`apply_state`](#method.apply_state).
+    pub fn persisted_state(&self) -> PersistedState {
+        PersistedState {
+            date: self.date.naive_local(),
+            view_date: self.view_date.naive_local(),
+            view_mode: self.view_mode,
+            week_start: self.week_start,
+            show_iso_weeks: self.show_iso_weeks,
+            earliest_date: self.earliest_date.as_ref().map(Date::naive_local),
+            latest_date: self.latest_date.as_ref().map(Date::naive_local),
+        }
+    }
+
+    /// Restores a snapshot previously returned by
+    /// [`persisted_state`](#method.persisted_state).
+    ///
+    /// Dates which do not exist in the time zone of this view are ignored.
+    pub fn apply_state(&mut self, state: &PersistedState) {
+        let tz = self.view_date.timezone();
+        let to_date = |date: &NaiveDate| tz.from_local_date(date).single();
+
+        self.set_earliest_date(state.earliest_date.as_ref().and_then(to_date));
+        self.set_latest_date(state.latest_date.as_ref().and_then(to_date));
+        if let Some(date) = to_date(&state.date) {
+            self.set_selected_date(date);
+        }
+        if let Some(date) = to_date(&state.view_date) {
+            self.set_view_date(date);
+        }
+        self.set_view_mode(state.view_mode);
+        self.set_week_start(state.week_start);
+        self.set_show_iso_weeks(state.show_iso_weeks);
+    }
+
     /// Sets the currently selected date of this view.
     pub fn set_selected_date(&mut self, mut date: Date<T>) {
         if let Some(ref earliest) = self.earliest_date {
@@ -193,6 +991,7 @@ where
         }
 
         self.view_date = date;
+        self.sticky_day = None;
     }
 
     /// Sets the visually selected date of this view.
@@ -202,6 +1001,47 @@ where
         self.with(|v| v.set_view_date(date))
     }
 
+    /// Moves the cursor to the Monday of the given ISO week and switches to
+    /// the `ViewMode::Month` view mode so the week is visible.
+    ///
+    /// If weeks can be selected, i.e. the lowest view mode is
+    /// `ViewMode::Week`, switches to that view mode instead and selects the
+    /// week without invoking any callbacks.
+    ///
+    /// The cursor is clamped to the earliest and latest selectable dates.
+    /// Weeks which do not exist in the given ISO year are ignored.
+    pub fn show_iso_week(&mut self, iso_year: i32, week: u32) {
+        let monday = NaiveDate::from_isoywd_opt(iso_year, week, Weekday::Mon)
+            .and_then(|date| self.view_date.timezone().from_local_date(&date).single());
+
+        if let Some(monday) = monday {
+            self.set_view_date(monday);
+            if self.lowest_view_mode == ViewMode::Week {
+                if let Some(start) = self.week_start_of(&self.view_date) {
+                    self.set_selected_date(start);
+                }
+                self.set_view_mode(ViewMode::Week);
+            } else {
+                self.set_view_mode(ViewMode::Month);
+            }
+        }
+    }
+
+    /// Selects the given date, moves the cursor onto it and switches to the
+    /// given view mode, e.g. to open the view on a month far in the future.
+    ///
+    /// The date is clamped to the earliest and latest selectable dates and
+    /// the view mode to the lowest and highest view modes. No callbacks are
+    /// invoked.
+    pub fn focus_date(&mut self, date: Date<T>, mode: ViewMode) {
+        self.set_selected_date(date);
+        self.set_view_date(self.date.clone());
+        self.view_mode = cmp::min(
+            cmp::max(mode, self.lowest_view_mode),
+            self.highest_view_mode,
+        );
+    }
+
     /// Sets the currently active view mode of this view.
     pub fn set_view_mode(&mut self, mode: ViewMode) {
         if mode >= self.lowest_view_mode && mode <= self.highest_view_mode {
@@ -266,632 +1106,5691 @@ where
         self.with(|v| v.set_highest_view_mode(mode))
     }
 
-    /// Sets and limits the earliest date selectable by this view.
-    pub fn set_earliest_date(&mut self, date: Option<Date<T>>) {
-        self.earliest_date = date;
-
-        if let Some(ref date) = self.earliest_date {
-            if self.date < *date {
-                self.date = date.clone();
-            }
-        }
+    /// Enable or disable skipping the `ViewMode::Year` view mode when
+    /// submitting a year in the `ViewMode::Decade` view mode.
+    ///
+    /// When enabled, the view switches straight to the lowest allowed view
+    /// mode, keeping the month of the cursor. With a lowest view mode of
+    /// `ViewMode::Year` this behaves the same as regular drilling.
+    pub fn set_fast_drill(&mut self, fast: bool) {
+        self.fast_drill = fast;
     }
 
-    /// Sets and limits the earliest date selectable by this view.
+    /// Enable or disable skipping the `ViewMode::Year` view mode when
+    /// submitting a year in the `ViewMode::Decade` view mode.
     ///
     /// Chainable variant.
-    pub fn earliest_date(self, date: Option<Date<T>>) -> Self {
-        self.with(|v| v.set_earliest_date(date))
+    pub fn fast_drill(self, fast: bool) -> Self {
+        self.with(|v| v.set_fast_drill(fast))
     }
 
-    /// Sets and limits the latest date selectable by this view.
-    pub fn set_latest_date(&mut self, date: Option<Date<T>>) {
-        self.latest_date = date;
-
-        if let Some(ref date) = self.latest_date {
-            if self.date > *date {
-                self.date = date.clone();
-            }
-        }
+    /// Enable or disable selection of an inclusive range of dates.
+    ///
+    /// When enabled, pressing `<Enter>` or left clicking a day in the
+    /// `ViewMode::Month` view mode first sets the anchor of the range and then
+    /// its other end, calling `on_range_submit` instead of `on_submit`. All
+    /// days within the range are highlighted, including those of adjacent
+    /// months.
+    pub fn set_range_select(&mut self, range_select: bool) {
+        self.range_select = range_select;
+        self.range_anchor = None;
     }
 
-    /// Sets and limits the latest date selectable by this view.
+    /// Enable or disable selection of an inclusive range of dates.
     ///
     /// Chainable variant.
-    pub fn latest_date(self, date: Option<Date<T>>) -> Self {
-        self.with(|v| v.set_latest_date(date))
+    pub fn range_select(self, range_select: bool) -> Self {
+        self.with(|v| v.set_range_select(range_select))
     }
 
-    /// Allows to change the default week start day of `WeekDay::Monday` to any other
-    /// [`WeekDay`](struct.WeekDay.html).
-    pub fn set_week_start(&mut self, day: WeekDay) {
-        self.week_start = day;
+    /// Enable or disable tinting the days of the week containing today in the
+    /// `ViewMode::Month` view mode.
+    ///
+    /// The week's extent follows the configured week start day. The cursor,
+    /// selection and today's date itself are drawn as usual.
+    pub fn set_highlight_today_week(&mut self, highlight: bool) {
+        self.highlight_today_week = highlight;
     }
 
-    /// Allows to change the default week start day of `WeekDay::Monday` to any other
-    /// [`WeekDay`](struct.WeekDay.html).
+    /// Enable or disable tinting the days of the week containing today in the
+    /// `ViewMode::Month` view mode.
     ///
     /// Chainable variant.
-    pub fn week_start(self, day: WeekDay) -> Self {
-        self.with(|v| v.set_week_start(day))
+    pub fn highlight_today_week(self, highlight: bool) -> Self {
+        self.with(|v| v.set_highlight_today_week(highlight))
     }
 
-    /// Show or hide ISO week numbers in the `ViewMode::Month` view mode.
+    /// Lock or unlock the year in the `ViewMode::Year` view mode.
     ///
-    /// ISO week numbers only make sense with a week start day of `WeekDay::Monday`.
-    pub fn set_show_iso_weeks(&mut self, show: bool) {
-        self.show_iso_weeks = show;
+    /// When locked, `<PageUp>` and `<PageDown>` do nothing and the cursor
+    /// cannot move past the first or last month of the year. The today key
+    /// moves the cursor to today's month within the locked year.
+    pub fn set_year_locked(&mut self, locked: bool) {
+        self.year_locked = locked;
     }
 
-    /// Show or hide ISO week numbers in the `ViewMode::Month` view mode.
+    /// Lock or unlock the year in the `ViewMode::Year` view mode.
     ///
-    /// ISO week numbers only make sense with a week start day of `WeekDay::Monday`.
+    /// Chainable variant.
+    pub fn year_locked(self, locked: bool) -> Self {
+        self.with(|v| v.set_year_locked(locked))
+    }
+
+    /// Enable or disable selection of multiple dates.
+    ///
+    /// When enabled, pressing `<Enter>` or left clicking a day in the
+    /// `ViewMode::Month` view mode toggles whether it is selected, instead of
+    /// replacing the selected date and calling `on_submit`.
+    pub fn set_multi_select(&mut self, multi_select: bool) {
+        self.multi_select = multi_select;
+    }
+
+    /// Enable or disable selection of multiple dates.
     ///
     /// Chainable variant.
-    pub fn show_iso_weeks(self, show: bool) -> Self {
-        self.with(|v| v.set_show_iso_weeks(show))
+    pub fn multi_select(self, multi_select: bool) -> Self {
+        self.with(|v| v.set_multi_select(multi_select))
     }
 
-    /// Sets a callback to be used when `<Enter>` is pressed to select a date.
-    pub fn set_on_submit<F>(&mut self, cb: F)
-    where
-        F: Fn(&mut Cursive, &Date<T>) + Send + Sync + 'static,
-    {
-        self.on_submit = Some(Arc::new(move |s, date| cb(s, date)));
+    /// Enable or disable committing with a quick double press of `<Enter>`
+    /// above the lowest view mode.
+    ///
+    /// When enabled, pressing `<Enter>` again right after drilling down from
+    /// a year or month, without moving the cursor, submits the first day of
+    /// that year or month instead of drilling down any further.
+    pub fn set_enter_commits_immediately(&mut self, commits: bool) {
+        self.enter_commits_immediately = commits;
+        self.last_drill = None;
     }
 
-    /// Sets a callback to be used when `<Enter>` is pressed to select a date.
+    /// Enable or disable committing with a quick double press of `<Enter>`
+    /// above the lowest view mode.
     ///
     /// Chainable variant.
-    pub fn on_submit<F>(self, cb: F) -> Self
-    where
-        F: Fn(&mut Cursive, &Date<T>) + Send + Sync + 'static,
-    {
-        self.with(|v| v.set_on_submit(cb))
+    pub fn enter_commits_immediately(self, commits: bool) -> Self {
+        self.with(|v| v.set_enter_commits_immediately(commits))
     }
 
-    /// Sets a callback to be used when an a new date is visually selected.
-    pub fn set_on_select<F>(&mut self, cb: F)
-    where
-        F: Fn(&mut Cursive, &Date<T>) + Send + Sync + 'static,
-    {
-        self.on_select = Some(Arc::new(move |s, date| cb(s, date)));
+    /// Enable or disable remembering the day of the month when navigating
+    /// between months or years.
+    ///
+    /// By default, moving from January 31st to February clamps the day to
+    /// the 28th and moving on to March keeps it there. When enabled, the
+    /// originally intended day is restored once a month is long enough,
+    /// landing on March 31st instead.
+    pub fn set_sticky_day_of_month(&mut self, sticky: bool) {
+        self.sticky_day_of_month = sticky;
+        self.sticky_day = None;
     }
 
-    /// Sets a callback to be used when an a new date is visually selected.
+    /// Enable or disable remembering the day of the month when navigating
+    /// between months or years.
     ///
     /// Chainable variant.
-    pub fn on_select<F>(self, cb: F) -> Self
-    where
-        F: Fn(&mut Cursive, &Date<T>) + Send + Sync + 'static,
-    {
-        self.with(|v| v.set_on_select(cb))
+    pub fn sticky_day_of_month(self, sticky: bool) -> Self {
+        self.with(|v| v.set_sticky_day_of_month(sticky))
     }
-}
 
-impl<T: TimeZone + Send + Sync, L: Locale + Send + Sync + 'static> CalendarView<T, L>
-where
-    T::Offset: Send + Sync,
-{
-    fn draw_month(&self, printer: &Printer<'_, '_>) {
-        let year = self.view_date.year();
-        let month: Month = self.view_date.month0().into();
-        let month_start = self.view_date.with_day0(0).unwrap();
+    /// Enable or disable selecting any available day of the current month
+    /// with a single left click in the `ViewMode::Month` view mode.
+    ///
+    /// By default, the first click only moves the cursor and a second click
+    /// selects the day. Clicks onto days of adjacent months always only
+    /// navigate.
+    pub fn set_click_selects_any(&mut self, selects: bool) {
+        self.click_selects_any = selects;
+    }
 
-        let active_day = self.date.day0() as i32;
-        let view_day = self.view_date.day0() as i32;
+    /// Enable or disable selecting any available day of the current month
+    /// with a single left click in the `ViewMode::Month` view mode.
+    ///
+    /// Chainable variant.
+    pub fn click_selects_any(self, selects: bool) -> Self {
+        self.with(|v| v.set_click_selects_any(selects))
+    }
 
-        let d_month = self.date.month0() as i32 - self.view_date.month0() as i32;
-        let d_year = self.date.year() - year;
+    /// Enable or disable placing the cursor exactly onto a clicked day of an
+    /// adjacent month in the `ViewMode::Month` view mode.
+    ///
+    /// When enabled, the view switches to the clicked day's month with the
+    /// cursor on that day, so a second click or `<Enter>` selects it. Clicks
+    /// onto unavailable adjacent days are ignored instead of moving the
+    /// cursor to the nearest available date.
+    pub fn set_click_adjacent_centers(&mut self, centers: bool) {
+        self.click_adjacent_centers = centers;
+    }
 
-        let month_days = month.number_of_days(year);
-        let prev_month_days = month.prev_number_of_days(year);
-        let first_week_day: WeekDay = (month_start.weekday() as i32).into();
+    /// Enable or disable placing the cursor exactly onto a clicked day of an
+    /// adjacent month in the `ViewMode::Month` view mode.
+    ///
+    /// Chainable variant.
+    pub fn click_adjacent_centers(self, centers: bool) -> Self {
+        self.with(|v| v.set_click_adjacent_centers(centers))
+    }
 
-        // Draw Month Name
-        printer.print(
-            (0, 0),
-            &format!(
-                "{:^width$}",
-                format!("{} {}", L::month(month, true), year),
-                width = self.size.x
-            ),
-        );
+    /// Sets and limits the earliest date selectable by this view.
+    pub fn set_earliest_date(&mut self, date: Option<Date<T>>) {
+        self.earliest_date = date;
 
-        // Draw Weekdays
-        let h_offset = if self.show_iso_weeks { 3 } else { 0 };
-        let w_offset: i32 = self.week_start.into();
-        for i in 0..7 {
-            let week_day: WeekDay = (i + w_offset).into();
-            printer.print((h_offset + i * 3, 1), L::week_day(week_day, false));
+        if let Some(ref date) = self.earliest_date {
+            if self.date < *date {
+                self.date = date.clone();
+            }
         }
+    }
 
-        // Draw days
-        let d_shift = ((WeekDay::Monday as i32 - w_offset) + 7) % 7;
-        let d_offset = ((first_week_day as i32) + d_shift) % 7;
-
-        for (index, i) in (-d_offset..-d_offset + 42).enumerate() {
-            let (day_number, month_offset) = if i < 0 {
-                (prev_month_days + i, -1)
-            } else if i > month_days - 1 {
-                (i - month_days, 1)
-            } else {
-                (i, 0)
-            };
-
-            if let Some(exact_date) =
-                date_from_day_and_offsets(&self.view_date, Some(day_number), 0, month_offset, 0)
-            {
-                let color = if !self.date_available(&exact_date) {
-                    ColorStyle::tertiary()
-                } else if i < 0 {
-                    if active_day == prev_month_days + i && d_month == -1 && d_year == 0 {
-                        if self.enabled && printer.focused {
-                            ColorStyle::highlight_inactive()
-                        } else {
-                            ColorStyle::secondary()
-                        }
-                    } else {
-                        ColorStyle::secondary()
-                    }
-                } else if i > month_days - 1 {
-                    if active_day == i - month_days && d_month == 1 && d_year == 0 {
-                        if self.enabled && printer.focused {
-                            ColorStyle::highlight_inactive()
-                        } else {
-                            ColorStyle::secondary()
-                        }
-                    } else {
-                        ColorStyle::secondary()
-                    }
-                } else if view_day == i {
-                    if self.enabled && printer.focused {
-                        ColorStyle::highlight()
-                    } else {
-                        ColorStyle::highlight_inactive()
-                    }
-                } else if active_day == i && d_month == 0 && d_year == 0 {
-                    if self.enabled {
-                        ColorStyle::highlight_inactive()
-                    } else {
-                        ColorStyle::primary()
-                    }
-                } else {
-                    ColorStyle::primary()
-                };
+    /// Sets and limits the earliest date selectable by this view.
+    ///
+    /// Chainable variant.
+    pub fn earliest_date(self, date: Option<Date<T>>) -> Self {
+        self.with(|v| v.set_earliest_date(date))
+    }
 
-                // Draw day number
-                let (x, y) = (h_offset + (index as i32 % 7) * 3, 2 + (index as i32 / 7));
-                printer.with_color(color, |printer| {
-                    printer.print((x, y), &format!("{:>2}", day_number + 1));
-                });
+    /// Sets and limits the latest date selectable by this view.
+    pub fn set_latest_date(&mut self, date: Option<Date<T>>) {
+        self.latest_date = date;
 
-                // Draw ISO Weeks (Only makes sense when start_of_week is Monday)
-                if self.show_iso_weeks && index as i32 % 7 == 0 {
-                    let iso_week = exact_date.iso_week().week();
-                    printer.with_color(ColorStyle::title_secondary(), |printer| {
-                        printer.print((0, y), &format!("{:>2}", iso_week));
-                    });
-                }
+        if let Some(ref date) = self.latest_date {
+            if self.date > *date {
+                self.date = date.clone();
             }
         }
     }
 
-    fn draw_year(&self, printer: &Printer<'_, '_>) {
-        let active_month = self.date.month0();
-        let view_month = self.view_date.month0();
-        let year = self.view_date.year();
-        let d_year = self.date.year() - year;
+    /// Sets and limits the latest date selectable by this view.
+    ///
+    /// Chainable variant.
+    pub fn latest_date(self, date: Option<Date<T>>) -> Self {
+        self.with(|v| v.set_latest_date(date))
+    }
 
-        // Draw Year
-        printer.print(
-            (0, 0),
-            &format!("{:^width$}", format!("{}", year), width = self.size.x),
-        );
+    /// Sets a predicate which disables all dates for which it returns `true`,
+    /// e.g. public holidays or already booked days.
+    ///
+    /// Disabled dates cannot be submitted. Months and years in which all
+    /// dates are disabled cannot be selected either.
+    pub fn set_disabled_dates<F>(&mut self, f: F)
+    where
+        F: Fn(&Date<T>) -> bool + Send + Sync + 'static,
+    {
+        self.disabled_dates = Some(Arc::new(f));
+    }
 
-        // Draw Month Names
-        let h_offset = if self.show_iso_weeks { 2 } else { 0 };
-        for i in 0..12 {
-            let color = if !self.month_available(i, year) {
-                ColorStyle::tertiary()
-            } else if view_month == i {
-                if self.enabled && printer.focused {
-                    ColorStyle::highlight()
-                } else {
-                    ColorStyle::highlight_inactive()
-                }
-            } else if active_month == i && d_year == 0 {
-                if self.enabled && printer.focused {
-                    ColorStyle::highlight_inactive()
-                } else {
-                    ColorStyle::primary()
-                }
-            } else {
-                ColorStyle::primary()
-            };
+    /// Sets a predicate which disables all dates for which it returns `true`.
+    ///
+    /// Chainable variant.
+    pub fn disabled_dates<F>(self, f: F) -> Self
+    where
+        F: Fn(&Date<T>) -> bool + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_disabled_dates(f))
+    }
 
-            let (x, y) = (h_offset + (i as i32 % 4) * 5, 2 + (i as i32 / 4) * 2);
-            printer.with_color(color, |printer| {
-                printer.print((x, y), &format!("{:>4}", L::month(i.into(), false)));
-            });
-        }
+    /// Sets a list of individual dates which are disabled, e.g. holidays or
+    /// closures.
+    ///
+    /// Disabled dates cannot be submitted.
+    pub fn set_blackout_dates(&mut self, dates: HashSet<Date<T>>) {
+        self.blackout_dates = dates;
     }
 
-    fn draw_decade(&self, printer: &Printer<'_, '_>) {
-        let active_year = self.date.year();
-        let view_year = self.view_date.year();
-        let decade = view_year - (view_year % 10);
+    /// Sets a list of individual dates which are disabled.
+    ///
+    /// Chainable variant.
+    pub fn blackout_dates(self, dates: HashSet<Date<T>>) -> Self {
+        self.with(|v| v.set_blackout_dates(dates))
+    }
 
-        // Draw Year Range
-        printer.print(
-            (0, 0),
-            &format!(
-                "{:^width$}",
-                format!("{} - {}", decade, decade + 9),
-                width = self.size.x
-            ),
-        );
+    /// Adds an individual date to the list of disabled dates.
+    pub fn add_blackout_date(&mut self, date: Date<T>) {
+        self.blackout_dates.insert(date);
+    }
 
-        // Draw Years
-        let h_offset = if self.show_iso_weeks { 2 } else { 0 };
-        for (index, i) in (-1..12).enumerate() {
-            let year = decade + i;
-            let color = if !self.year_available(year) {
-                ColorStyle::tertiary()
-            } else if !(0..=9).contains(&i) {
-                if active_year == year {
-                    if self.enabled && printer.focused {
-                        ColorStyle::highlight_inactive()
-                    } else {
-                        ColorStyle::secondary()
-                    }
-                } else {
-                    ColorStyle::secondary()
-                }
-            } else if view_year == year {
-                if self.enabled && printer.focused {
-                    ColorStyle::highlight()
-                } else {
-                    ColorStyle::highlight_inactive()
-                }
-            } else if active_year == year {
-                if self.enabled {
-                    ColorStyle::highlight_inactive()
-                } else {
-                    ColorStyle::primary()
-                }
-            } else {
-                ColorStyle::primary()
-            };
+    /// Removes an individual date from the list of disabled dates.
+    pub fn remove_blackout_date(&mut self, date: &Date<T>) {
+        self.blackout_dates.remove(date);
+    }
 
-            let (x, y) = (
-                h_offset + (index as i32 % 4) * 5,
-                2 + (index as i32 / 4) * 2,
-            );
+    /// Sets and limits the earliest and latest date selectable by this view
+    /// from a range, e.g. `start..=end`, `start..` or `..=end`.
+    ///
+    /// Unbounded sides remove the respective limit. An exclusive end, as in
+    /// `start..end`, limits the selection to the day before `end`, so
+    /// `start..end` and `start..=end - 1 day` are equivalent.
+    ///
+    /// Ranges whose start lies after their end are used as if both dates were
+    /// given the other way around. Empty ranges, e.g. `date..date`, limit the
+    /// selection to their start date. An exclusive bound at the limit of the
+    /// supported date range is used as if it were inclusive.
+    pub fn set_date_range<R: RangeBounds<Date<T>>>(&mut self, range: R) {
+        let mut start = clone_bound(range.start_bound());
+        let mut end = clone_bound(range.end_bound());
 
-            printer.with_color(color, |printer| {
-                printer.print((x, y), &format!("{:>4}", year));
-            });
+        if let (
+            Bound::Included(start) | Bound::Excluded(start),
+            Bound::Included(end) | Bound::Excluded(end),
+        ) = (&mut start, &mut end)
+        {
+            if start > end {
+                mem::swap(start, end);
+            }
         }
-    }
 
-    fn date_available(&self, date: &Date<T>) -> bool {
-        if let Some(ref earliest) = self.earliest_date {
-            if *date < *earliest {
-                return false;
+        let earliest = match start {
+            Bound::Included(date) => Some(date),
+            Bound::Excluded(date) => {
+                Some(date_from_day_and_offsets(&date, None, 1, 0, 0).unwrap_or(date))
             }
-        }
+            Bound::Unbounded => None,
+        };
 
-        if let Some(ref latest) = self.latest_date {
-            if *date > *latest {
-                return false;
+        let mut latest = match end {
+            Bound::Included(date) => Some(date),
+            Bound::Excluded(date) => {
+                Some(date_from_day_and_offsets(&date, None, -1, 0, 0).unwrap_or(date))
+            }
+            Bound::Unbounded => None,
+        };
+
+        if let (Some(earliest), Some(latest)) = (&earliest, &mut latest) {
+            if *latest < *earliest {
+                *latest = earliest.clone();
             }
         }
 
-        true
+        self.set_earliest_date(earliest);
+        self.set_latest_date(latest);
     }
 
-    fn month_available(&self, month: u32, year: i32) -> bool {
-        if !self.year_available(year) {
-            return false;
-        }
+    /// Sets and limits the earliest and latest date selectable by this view
+    /// from a range.
+    ///
+    /// Chainable variant.
+    pub fn date_range<R: RangeBounds<Date<T>>>(self, range: R) -> Self {
+        self.with(|v| v.set_date_range(range))
+    }
 
-        if let Some(ref earliest) = self.earliest_date {
-            if year == earliest.year() && month < earliest.month0() {
-                return false;
-            }
-        }
+    /// Returns `true` if the given date can be selected in this view.
+    pub fn is_date_available(&self, date: &Date<T>) -> bool {
+        self.date_available(date)
+    }
 
-        if let Some(ref latest) = self.latest_date {
-            if year == latest.year() && month > latest.month0() {
-                return false;
-            }
+    /// Returns the reason why the given date cannot be selected, as described
+    /// by the callback set via
+    /// [`set_disabled_reason`](#method.set_disabled_reason).
+    ///
+    /// The callback is only consulted for dates which are not available.
+    pub fn get_disabled_reason(&self, date: &Date<T>) -> Option<String> {
+        if self.date_available(date) {
+            return None;
         }
+        self.disabled_reason.as_ref().and_then(|cb| cb(date))
+    }
 
-        true
+    /// Returns `true` if the given date lies within the month which is
+    /// currently shown in the `ViewMode::Month` view mode.
+    ///
+    /// Days outside of the current month are drawn as adjacent days.
+    pub fn is_current_month(&self, date: &Date<T>) -> bool {
+        date.year() == self.view_date.year() && date.month0() == self.view_date.month0()
     }
 
-    fn year_available(&self, year: i32) -> bool {
-        if let Some(ref earliest) = self.earliest_date {
-            if year < earliest.year() {
-                return false;
-            }
-        }
+    /// Returns the color the given date is drawn with in the
+    /// `ViewMode::Month` view mode, based on the current cursor, selection and
+    /// availability as well as the given focus state.
+    pub fn cell_color(&self, date: &Date<T>, focused: bool) -> ColorStyle {
+        let available = self.date_available(date);
+        let selected = self.date_selected(date);
 
-        if let Some(ref latest) = self.latest_date {
-            if year > latest.year() {
-                return false;
+        let color = if !available {
+            self.style.unavailable
+        } else if let Some((start, end)) = self
+            .highlighted_range()
+            .filter(|(start, end)| *date != self.view_date && date >= start && date <= end)
+        {
+            if *date == start || *date == end {
+                self.style.range_endpoint
+            } else {
+                self.style.range
             }
-        }
+        } else if self.view_mode == ViewMode::Week && self.same_week(date, &self.view_date) {
+            if self.enabled && focused {
+                self.style.cursor
+            } else {
+                self.style.selected
+            }
+        } else if self.view_mode == ViewMode::Week && self.same_week(date, &self.date) {
+            if self.enabled {
+                self.style.selected
+            } else {
+                self.style.normal
+            }
+        } else if !self.is_current_month(date) {
+            if selected && self.enabled && focused {
+                self.style.selected
+            } else {
+                self.style.adjacent
+            }
+        } else if *date == self.view_date {
+            if self.enabled && focused {
+                self.style.cursor
+            } else {
+                self.style.selected
+            }
+        } else if selected {
+            if self.enabled {
+                self.style.selected
+            } else {
+                self.style.normal
+            }
+        } else if let Some(style) = self.day_decorator.as_ref().and_then(|cb| cb(date)) {
+            style
+        } else if *date == self.today {
+            self.style.today
+        } else if self.highlight_today_week && self.same_week(date, &self.today) {
+            self.style.today_week
+        } else {
+            self.style.normal
+        };
 
-        true
+        self.disabled_color(color, available, selected)
     }
 
-    fn submit(&mut self) -> EventResult
+    /// Returns the `(day, month, year)` offset the given navigation event
+    /// applies to the visually selected date in the current view mode.
+    ///
+    /// Returns `None` for events which do not navigate.
+    pub fn navigation_offset(&self, event: &Event) -> Option<(i32, i32, i32)> {
+        self.action_offset(self.key_bindings.action(event)?)
+    }
+
+    /// Moves the cursor `dx` cells to the right (or left, if negative) and
+    /// `dy` rows down (or up, if negative) and returns the new cursor position.
+    ///
+    /// Applies the same navigation rules as the arrow keys, including the
+    /// earliest and latest dates, but does not invoke any callbacks. Useful
+    /// for testing without a cursive backend.
+    pub fn move_selection(&mut self, dx: i32, dy: i32) -> Date<T>
     where
         T: 'static,
     {
-        if self.view_mode == self.lowest_view_mode {
-            self.date = self.view_date.clone();
-
-            if self.on_submit.is_some() {
-                let cb = self.on_submit.clone().unwrap();
-                let date = self.date.clone();
-                return EventResult::Consumed(Some(Callback::from_fn(move |s| cb(s, &date))));
-            }
-        } else {
-            self.view_mode = match self.view_mode {
-                ViewMode::Month | ViewMode::Year => ViewMode::Month,
-                ViewMode::Decade => ViewMode::Year,
+        if self.enabled {
+            let h_action = if dx < 0 {
+                KeyAction::MovePrev
+            } else {
+                KeyAction::MoveNext
+            };
+            let v_action = if dy < 0 {
+                KeyAction::MoveUp
+            } else {
+                KeyAction::MoveDown
             };
+
+            for _ in 0..dx.abs() {
+                self.navigate(h_action);
+            }
+            for _ in 0..dy.abs() {
+                self.navigate(v_action);
+            }
         }
-        EventResult::Consumed(None)
+        self.view_date.clone()
     }
-}
 
-impl<T: TimeZone + Send + Sync + 'static, L: Locale + Send + Sync + 'static> View
-    for CalendarView<T, L>
-where
-    T::Offset: Send + Sync,
-{
-    fn draw(&self, printer: &Printer<'_, '_>) {
-        match self.view_mode {
-            ViewMode::Month => self.draw_month(printer),
-            ViewMode::Year => self.draw_year(printer),
-            ViewMode::Decade => self.draw_decade(printer),
+    /// Submits the cursor position as if `<Enter>` was pressed and returns
+    /// the submitted date.
+    ///
+    /// Returns `None` if no date was submitted, e.g. because the view
+    /// switched to the next lower view mode instead. Does not invoke any
+    /// callbacks. Useful for testing without a cursive backend.
+    pub fn submit_current(&mut self) -> Option<Date<T>>
+    where
+        T: 'static,
+    {
+        if !self.enabled {
+            return None;
         }
-    }
 
-    fn required_size(&mut self, _: Vec2) -> Vec2 {
-        self.size = if self.show_iso_weeks {
-            (23, 8).into()
+        self.submitted = false;
+        self.submit();
+        if self.submitted {
+            Some(self.date.clone())
         } else {
-            (20, 8).into()
-        };
-        self.size
-    }
-
-    fn take_focus(&mut self, _: Direction) -> Result<EventResult, CannotFocus> {
-        self.enabled.then(EventResult::consumed).ok_or(CannotFocus)
+            None
+        }
     }
 
-    fn on_event(&mut self, event: Event) -> EventResult {
-        if !self.enabled {
-            return EventResult::Ignored;
-        }
+    /// The `(day, month, year)` offset the given navigation action applies
+    /// to the visually selected date in the current view mode.
+    fn action_offset(&self, action: KeyAction) -> Option<(i32, i32, i32)> {
+        // Left and right stay within the highlighted week
+        let week_day = match self.week_start_of(&self.view_date) {
+            Some(start) => self
+                .view_date
+                .clone()
+                .signed_duration_since(start)
+                .num_days() as i32,
+            None => 0,
+        };
 
-        let last_view_date = self.view_date.clone();
-        let offsets = match event {
-            Event::Key(Key::Up) => Some(match self.view_mode {
-                ViewMode::Month => (-7, 0, 0),
+        match action {
+            KeyAction::MoveUp => Some(match self.view_mode {
+                ViewMode::Week | ViewMode::Month => (-7, 0, 0),
                 ViewMode::Year => (0, -4, 0),
                 ViewMode::Decade => (0, 0, -4),
             }),
-            Event::Key(Key::Down) => Some(match self.view_mode {
-                ViewMode::Month => (7, 0, 0),
+            KeyAction::MoveDown => Some(match self.view_mode {
+                ViewMode::Week | ViewMode::Month => (7, 0, 0),
                 ViewMode::Year => (0, 4, 0),
                 ViewMode::Decade => (0, 0, 4),
             }),
-            Event::Key(Key::Right) => Some(match self.view_mode {
+            KeyAction::MoveNext => Some(match self.view_mode {
+                ViewMode::Week => (if week_day < 6 { 1 } else { 0 }, 0, 0),
                 ViewMode::Month => (1, 0, 0),
                 ViewMode::Year => (0, 1, 0),
                 ViewMode::Decade => (0, 0, 1),
             }),
-            Event::Key(Key::Left) => Some(match self.view_mode {
+            KeyAction::MovePrev => Some(match self.view_mode {
+                ViewMode::Week => (if week_day > 0 { -1 } else { 0 }, 0, 0),
                 ViewMode::Month => (-1, 0, 0),
                 ViewMode::Year => (0, -1, 0),
                 ViewMode::Decade => (0, 0, -1),
             }),
-            Event::Key(Key::PageUp) => Some(match self.view_mode {
-                ViewMode::Month => (0, -1, 0),
+            KeyAction::PageBack => Some(match self.view_mode {
+                ViewMode::Week | ViewMode::Month => (0, -1, 0),
                 ViewMode::Year => (0, 0, -1),
-                ViewMode::Decade => (0, 0, -10),
+                ViewMode::Decade => (0, 0, -(self.decade_size as i32)),
             }),
-            Event::Key(Key::PageDown) => Some(match self.view_mode {
-                ViewMode::Month => (0, 1, 0),
+            KeyAction::PageForward => Some(match self.view_mode {
+                ViewMode::Week | ViewMode::Month => (0, 1, 0),
                 ViewMode::Year => (0, 0, 1),
-                ViewMode::Decade => (0, 0, 10),
+                ViewMode::Decade => (0, 0, self.decade_size as i32),
             }),
-            Event::Key(Key::Backspace) => {
-                if self.view_mode < self.highest_view_mode {
-                    self.view_mode = match self.view_mode {
-                        ViewMode::Month => ViewMode::Year,
-                        ViewMode::Year | ViewMode::Decade => ViewMode::Decade,
-                    };
-                }
-                None
-            }
-            Event::Key(Key::Enter) => {
-                return self.submit();
-            }
-            Event::Mouse {
-                position,
-                offset,
-                event: MouseEvent::Press(btn),
-            } => {
-                let position = match position.checked_sub(offset) {
-                    Some(position) => position,
-                    None => return EventResult::Ignored,
-                };
-                match self.view_mode {
-                    ViewMode::Decade => {
-                        let h_offset = if self.show_iso_weeks { 2 } else { 0 };
-                        if position.y < 2
-                            || position.y % 2 != 0
-                            || position.x < h_offset
-                            || (position.x - h_offset) % 5 == 4
-                        {
-                            return EventResult::Ignored;
-                        }
-                        let cell_index = (position.x - h_offset) / 5 + (position.y - 2) * 2;
-                        let current_index = 1 + last_view_date.year() % 10;
+            KeyAction::Ascend | KeyAction::Submit => None,
+        }
+    }
 
-                        let offset = cell_index as i32 - current_index;
-                        if offset == 0 && btn == MouseButton::Left {
-                            return self.submit();
-                        }
-                        Some((0, 0, offset))
-                    }
-                    ViewMode::Year => {
-                        let h_offset = if self.show_iso_weeks { 2 } else { 0 };
-                        if position.y < 2
-                            || position.y % 2 != 0
-                            || position.x < h_offset
-                            || (position.x - h_offset) % 5 == 4
-                        {
-                            return EventResult::Ignored;
-                        }
-                        let month =
-                            4 * (position.y.saturating_sub(2) / 2) + ((position.x - h_offset) / 5);
-                        let offset = month as i32 - last_view_date.month0() as i32;
-                        if offset == 0 && btn == MouseButton::Left {
-                            return self.submit();
-                        }
-                        Some((0, offset, 0))
-                    }
-                    ViewMode::Month => {
-                        let h_offset = if self.show_iso_weeks { 3 } else { 0 };
+    /// Returns the number of rows in the `ViewMode::Month` grid which
+    /// contain at least one day of the currently viewed month.
+    pub fn week_row_count(&self) -> usize {
+        let month: Month = self.view_date.month0().into();
+        let cells = self.month_day_offset() + month.number_of_days(self.view_date.year());
+        cmp::min(((cells + 6) / 7) as usize, 6)
+    }
 
-                        if position.y < 2
-                            || position.x < h_offset
-                            || (position.x - h_offset) % 3 == 2
-                        {
-                            return EventResult::Ignored;
-                        }
+    /// Returns the dates of the `ViewMode::Month` grid of the currently
+    /// viewed month as week rows, in the order they are drawn.
+    ///
+    /// Contains as many rows as are drawn, see
+    /// [`set_fixed_height`](#method.set_fixed_height). Cells of adjacent
+    /// months are `None` if they are hidden, see
+    /// [`set_show_adjacent_days`](#method.set_show_adjacent_days).
+    pub fn month_grid(&self) -> Vec<Vec<Option<Date<T>>>> {
+        let d_offset = self.month_day_offset();
+        (0..self.grid_rows() as i32)
+            .map(|row| {
+                (0..7)
+                    .map(|column| {
+                        let offset = row * 7 + column - d_offset;
+                        date_from_day_and_offsets(&self.view_date, Some(0), offset, 0, 0)
+                            .filter(|date| self.show_adjacent_days || self.is_current_month(date))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the number of days within the currently viewed month which
+    /// can be selected.
+    pub fn available_day_count(&self) -> usize {
+        (0..self.total_day_count() as u32)
+            .filter_map(|day0| self.view_date.with_day0(day0))
+            .filter(|date| self.date_available(date))
+            .count()
+    }
 
-                        let cell_index = (position.x - h_offset) / 3 + 7 * (position.y - 2);
+    /// Returns the number of days within the currently viewed month.
+    pub fn total_day_count(&self) -> usize {
+        let month: Month = self.view_date.month0().into();
+        month.number_of_days(self.view_date.year()) as usize
+    }
 
-                        let month_start = self.view_date.with_day0(0).unwrap();
-                        let first_week_day: WeekDay = (month_start.weekday() as i32).into();
-                        let w_offset: i32 = self.week_start.into();
-                        let d_shift = ((WeekDay::Monday as i32 - w_offset) + 7) % 7;
-                        let d_offset = ((first_week_day as i32) + d_shift) % 7;
-                        let current_index = last_view_date.day0() as i32 + d_offset;
+    /// Returns the 0-based index of the `ViewMode::Month` grid row which
+    /// contains the currently selected date.
+    ///
+    /// Returns `None` if the selected date does not lie within the currently
+    /// viewed month.
+    pub fn selected_row(&self) -> Option<usize> {
+        if !self.is_current_month(&self.date) {
+            return None;
+        }
+        let index = self.month_day_offset() + self.date.day0() as i32;
+        if index < 0 || index >= 7 * self.grid_rows() as i32 {
+            return None;
+        }
+        Some((index / 7) as usize)
+    }
 
-                        let offset = cell_index as i32 - current_index;
-                        if offset == 0 && btn == MouseButton::Left {
-                            return self.submit();
-                        }
-                        Some((offset, 0, 0))
-                    }
-                }
-            }
-            _ => return EventResult::Ignored,
+    /// Returns the years shown in the `ViewMode::Decade` view mode in display
+    /// order, each paired with whether it can be selected.
+    ///
+    /// This includes the last year of the previous and the first two years of
+    /// the next decade, see [`set_decade_size`](#method.set_decade_size).
+    pub fn visible_years(&self) -> Vec<(i32, bool)> {
+        let decade = self.decade_start();
+        (-1..self.decade_size as i32 + 2)
+            .map(|i| decade + i)
+            .map(|year| (year, self.year_available(year)))
+            .collect()
+    }
+
+    /// Renders a compact, read-only representation of `weeks` weeks, starting
+    /// with the week containing the visually selected date.
+    ///
+    /// Each line lists the day numbers of one week, separated by spaces.
+    pub fn render_agenda(&self, weeks: usize) -> Vec<String> {
+        let start = match self.week_start_of(&self.view_date) {
+            Some(start) => start,
+            None => return Vec::new(),
         };
 
-        if let Some((day, month, year)) = offsets {
-            if let Some(date) = date_from_day_and_offsets(&last_view_date, None, day, month, year) {
-                self.set_view_date(date);
+        (0..weeks as i32)
+            .map(|week| {
+                (0..7)
+                    .filter_map(|day| date_from_day_and_offsets(&start, None, week * 7 + day, 0, 0))
+                    .map(|date| format!("{:>2}", date.day()))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect()
+    }
+
+    /// Returns the first date that can actually be selected, scanning forward
+    /// from the earliest date.
+    ///
+    /// Without an earliest date, the scan covers about ten years on either
+    /// side of the visually selected date instead.
+    ///
+    /// Returns `None` if no available date is found within the search window.
+    pub fn first_selectable(&self) -> Option<Date<T>> {
+        let (mut date, days) = match self.earliest_date {
+            Some(ref earliest) => (earliest.clone(), SEARCH_WINDOW_DAYS),
+            None => (self.search_window_edge(false), 2 * SEARCH_WINDOW_DAYS + 1),
+        };
+        for _ in 0..days {
+            if self.date_available(&date) {
+                return Some(date);
             }
+            date = date.succ_opt()?;
         }
+        None
+    }
 
-        if self.view_date != last_view_date {
-            let date = self.view_date.clone();
-            EventResult::Consumed(
-                self.on_select
-                    .clone()
-                    .map(|cb| Callback::from_fn(move |s| cb(s, &date))),
-            )
-        } else {
-            EventResult::Consumed(None)
+    /// Returns the last date that can actually be selected, scanning backward
+    /// from the latest date.
+    ///
+    /// Without a latest date, the scan covers about ten years on either side
+    /// of the visually selected date instead.
+    ///
+    /// Returns `None` if no available date is found within the search window.
+    pub fn last_selectable(&self) -> Option<Date<T>> {
+        let (mut date, days) = match self.latest_date {
+            Some(ref latest) => (latest.clone(), SEARCH_WINDOW_DAYS),
+            None => (self.search_window_edge(true), 2 * SEARCH_WINDOW_DAYS + 1),
+        };
+        for _ in 0..days {
+            if self.date_available(&date) {
+                return Some(date);
+            }
+            date = date.pred_opt()?;
         }
+        None
     }
-}
 
-// Helpers --------------------------------------------------------------------
-fn date_from_day_and_offsets<T: TimeZone>(
-    date: &Date<T>,
-    set_day: Option<i32>,
-    day_offset: i32,
-    month_offset: i32,
-    year_offset: i32,
-) -> Option<Date<T>> {
-    let mut year = date.year() + year_offset;
-    let mut month = date.month0() as i32;
+    /// Switches the localization of this view at runtime, overriding the
+    /// view's [`Locale`](trait.Locale.html) type parameter.
+    ///
+    /// Accepts a [`DynLocale`](enum.DynLocale.html) or any boxed
+    /// [`RuntimeLocale`](trait.RuntimeLocale.html). The new month and week
+    /// day names are shown on the next redraw.
+    pub fn set_locale<D: Into<DynLocale>>(&mut self, locale: D) {
+        self.locale = Some(locale.into());
+        self.invalidate();
+    }
 
-    month += month_offset;
+    /// Switches the localization of this view at runtime, overriding the
+    /// view's [`Locale`](trait.Locale.html) type parameter.
+    ///
+    /// Chainable variant.
+    pub fn locale<D: Into<DynLocale>>(self, locale: D) -> Self {
+        self.with(|v| v.set_locale(locale))
+    }
 
-    while month < 0 {
-        year -= 1;
-        month += 12;
+    /// Applies the defaults of the view's [`Locale`](trait.Locale.html).
+    ///
+    /// Settings which were changed explicitly are left untouched, so the week
+    /// start day is taken from an explicit
+    /// [`CalendarView::set_week_start`](struct.CalendarView.html#method.set_week_start)
+    /// call first, then from the locale and finally defaults to `WeekDay::Monday`.
+    pub fn apply_locale_defaults(&mut self) {
+        if !self.week_start_explicit {
+            self.week_start = self.runtime_locale().week_start();
+        }
     }
 
-    while month >= 12 {
-        month -= 12;
-        year += 1;
+    /// Allows to change the default week start day of `WeekDay::Monday` to any other
+    /// [`WeekDay`](struct.WeekDay.html).
+    ///
+    /// Takes precedence over the week start day of the view's
+    /// [`Locale`](trait.Locale.html).
+    pub fn set_week_start(&mut self, day: WeekDay) {
+        self.week_start = day;
+        self.week_start_explicit = true;
     }
 
-    let d = date
-        .with_day0(0)?
-        .with_year(year)?
-        .with_month0(month as u32)?;
+    /// Allows to change the default week start day of `WeekDay::Monday` to any other
+    /// [`WeekDay`](struct.WeekDay.html).
+    ///
+    /// Chainable variant.
+    pub fn week_start(self, day: WeekDay) -> Self {
+        self.with(|v| v.set_week_start(day))
+    }
 
-    let month: Month = d.month0().into();
-    let number_of_days = month.number_of_days(year);
+    /// Disables all dates which fall onto one of the given
+    /// [`WeekDay`](struct.WeekDay.html)s, e.g. to prevent selection of weekends.
+    pub fn set_disabled_week_days(&mut self, days: Vec<WeekDay>) {
+        self.disabled_week_days = days;
+    }
 
-    let mut day = set_day.unwrap_or_else(|| cmp::min(number_of_days - 1, date.day0() as i32));
+    /// Disables all dates which fall onto one of the given
+    /// [`WeekDay`](struct.WeekDay.html)s, e.g. to prevent selection of weekends.
+    ///
+    /// Chainable variant.
+    pub fn disabled_week_days(self, days: Vec<WeekDay>) -> Self {
+        self.with(|v| v.set_disabled_week_days(days))
+    }
 
-    day += day_offset;
-    if day < 0 {
-        day += month.prev_number_of_days(year);
-        date_from_day_and_offsets(&d, Some(day), 0, -1, 0)
-    } else if day >= number_of_days {
-        day -= number_of_days;
-        date_from_day_and_offsets(&d, Some(day), 0, 1, 0)
-    } else {
-        d.with_day0(day as u32)
+    /// Returns the week days on which all dates are disabled.
+    pub fn get_disabled_week_days(&self) -> &[WeekDay] {
+        &self.disabled_week_days
     }
-}
 
-#[test]
-fn test_offsets() {
-    let date = Utc.ymd(1969, 7, 20);
+    /// Enable or disable skipping of disabled week days when navigating
+    /// with the arrow keys in the `ViewMode::Month` view mode.
+    ///
+    /// When enabled, the cursor moves on to the nearest enabled day in the
+    /// direction of movement.
+    pub fn set_skip_disabled_on_nav(&mut self, skip: bool) {
+        self.skip_disabled_on_nav = skip;
+    }
 
-    // Moon landing
-    assert_eq!(
-        Some(Utc.ymd(1969, 7, 20)),
-        date_from_day_and_offsets(&date, None, 0, 0, 0)
-    );
+    /// Enable or disable skipping of disabled week days when navigating
+    /// with the arrow keys in the `ViewMode::Month` view mode.
+    ///
+    /// Chainable variant.
+    pub fn skip_disabled_on_nav(self, skip: bool) -> Self {
+        self.with(|v| v.set_skip_disabled_on_nav(skip))
+    }
 
-    // Mission start
-    assert_eq!(
-        Some(Utc.ymd(1969, 7, 16)),
-        date_from_day_and_offsets(&date, None, -4, 0, 0)
-    );
+    /// Sets how navigating with the arrow keys behaves at the earliest and
+    /// latest selectable dates. Defaults to `NavMode::Clamp`.
+    ///
+    /// `NavMode::Wrap` wraps within the window allowed by
+    /// [`set_earliest_date`](#method.set_earliest_date) and
+    /// [`set_latest_date`](#method.set_latest_date), not the full calendar:
+    /// moving past the last selectable day returns to the first one and vice
+    /// versa. Without both limits set, navigation clamps as usual.
+    pub fn set_navigation_mode(&mut self, mode: NavMode) {
+        self.navigation_mode = mode;
+    }
 
-    // Mission end
-    assert_eq!(
-        Some(Utc.ymd(1969, 7, 24)),
-        date_from_day_and_offsets(&date, None, 4, 0, 0)
-    );
+    /// Sets how navigating with the arrow keys behaves at the earliest and
+    /// latest selectable dates.
+    ///
+    /// Chainable variant.
+    pub fn navigation_mode(self, mode: NavMode) -> Self {
+        self.with(|v| v.set_navigation_mode(mode))
+    }
 
-    // Quarantine lifted
-    assert_eq!(
-        Some(Utc.ymd(1969, 8, 10)),
-        date_from_day_and_offsets(&date, None, 21, 0, 0)
-    );
-    assert_eq!(
-        Some(Utc.ymd(1969, 8, 10)),
-        date_from_day_and_offsets(&date, None, -10, 1, 0)
-    );
+    /// Restrict the cursor and selection to business days, i.e. Monday to
+    /// Friday.
+    ///
+    /// When enabled, weekends are unavailable and keyboard as well as mouse
+    /// navigation in the `ViewMode::Month` view mode snaps to the nearest
+    /// business day. Weekends can no longer be submitted.
+    pub fn set_business_days_only(&mut self, business_days_only: bool) {
+        self.business_days_only = business_days_only;
+    }
+
+    /// Restrict the cursor and selection to business days, i.e. Monday to
+    /// Friday.
+    ///
+    /// Chainable variant.
+    pub fn business_days_only(self, business_days_only: bool) -> Self {
+        self.with(|v| v.set_business_days_only(business_days_only))
+    }
+
+    /// Show or hide ISO week numbers in the `ViewMode::Month` view mode.
+    ///
+    /// Shorthand for [`set_week_numbering`](#method.set_week_numbering) with
+    /// `WeekNumbering::Iso` or `WeekNumbering::None`.
+    pub fn set_show_iso_weeks(&mut self, show: bool) {
+        self.set_week_numbering(if show {
+            WeekNumbering::Iso
+        } else {
+            WeekNumbering::None
+        });
+    }
+
+    /// Show or hide ISO week numbers in the `ViewMode::Month` view mode.
+    ///
+    /// ISO week numbers only make sense with a week start day of `WeekDay::Monday`.
+    ///
+    /// Chainable variant.
+    pub fn show_iso_weeks(self, show: bool) -> Self {
+        self.with(|v| v.set_show_iso_weeks(show))
+    }
+
+    /// Sets the week numbering scheme shown next to the rows of the
+    /// `ViewMode::Month` grid. Defaults to `WeekNumbering::None`.
+    ///
+    /// The numbers are computed for the configured week start day, so each
+    /// row shows the number of the week the majority of its days belong to.
+    pub fn set_week_numbering(&mut self, numbering: WeekNumbering) {
+        self.week_numbering = numbering;
+        self.show_iso_weeks = numbering != WeekNumbering::None;
+        self.invalidate();
+    }
+
+    /// Sets the week numbering scheme shown next to the rows of the
+    /// `ViewMode::Month` grid.
+    ///
+    /// Chainable variant.
+    pub fn week_numbering(self, numbering: WeekNumbering) -> Self {
+        self.with(|v| v.set_week_numbering(numbering))
+    }
+
+    /// Sets the vertical alignment of the calendar within the space allocated
+    /// by its parent.
+    pub fn set_vertical_align(&mut self, align: VAlign) {
+        self.v_align = align;
+    }
+
+    /// Sets the vertical alignment of the calendar within the space allocated
+    /// by its parent.
+    ///
+    /// Chainable variant.
+    pub fn vertical_align(self, align: VAlign) -> Self {
+        self.with(|v| v.set_vertical_align(align))
+    }
+
+    /// Enable or disable centering the calendar within the space allocated
+    /// by its parent.
+    ///
+    /// When enabled, the view requests all of the available space and draws
+    /// the calendar centered both horizontally and vertically, overriding
+    /// [`set_vertical_align`](#method.set_vertical_align).
+    pub fn set_center_in_parent(&mut self, center: bool) {
+        self.center_in_parent = center;
+        self.invalidate();
+    }
+
+    /// Enable or disable centering the calendar within the space allocated
+    /// by its parent.
+    ///
+    /// Chainable variant.
+    pub fn center_in_parent(self, center: bool) -> Self {
+        self.with(|v| v.set_center_in_parent(center))
+    }
+
+    /// Always reserve the space of the ISO week column, so that toggling
+    /// [`set_show_iso_weeks`](#method.set_show_iso_weeks) does not move the
+    /// day columns.
+    pub fn set_reserve_iso_column(&mut self, reserve: bool) {
+        self.reserve_iso_column = reserve;
+        self.invalidate();
+    }
+
+    /// Always reserve the space of the ISO week column.
+    ///
+    /// Chainable variant.
+    pub fn reserve_iso_column(self, reserve: bool) -> Self {
+        self.with(|v| v.set_reserve_iso_column(reserve))
+    }
+
+    /// Number weeks relative to the week containing `anchor` instead of
+    /// using ISO week numbers, e.g. for academic "teaching week" calendars.
+    ///
+    /// The week of the `anchor` is numbered `start_number` and the number
+    /// increments with each following week. Weeks before the anchor as well
+    /// as negative numbers are left blank.
+    ///
+    /// The numbers are shown in place of the ISO week numbers and thus require
+    /// `set_show_iso_weeks(true)`.
+    pub fn set_week_number_base(&mut self, anchor: Date<T>, start_number: i32) {
+        self.week_number_base = Some((anchor, start_number));
+    }
+
+    /// Number weeks relative to the week containing `anchor` instead of
+    /// using ISO week numbers.
+    ///
+    /// Chainable variant.
+    pub fn week_number_base(self, anchor: Date<T>, start_number: i32) -> Self {
+        self.with(|v| v.set_week_number_base(anchor, start_number))
+    }
+
+    /// Reverts to ISO week numbers after a previous call to
+    /// [`set_week_number_base`](#method.set_week_number_base).
+    pub fn clear_week_number_base(&mut self) {
+        self.week_number_base = None;
+    }
+
+    /// Show the abbreviated names of the previous and next month at the edges
+    /// of the `ViewMode::Month` header.
+    ///
+    /// The names are omitted whenever they do not fit next to the current
+    /// month name. Clicking them navigates to the respective month.
+    pub fn set_show_adjacent_month_names(&mut self, show: bool) {
+        self.show_adjacent_month_names = show;
+    }
+
+    /// Show the abbreviated names of the previous and next month at the edges
+    /// of the `ViewMode::Month` header.
+    ///
+    /// Chainable variant.
+    pub fn show_adjacent_month_names(self, show: bool) -> Self {
+        self.with(|v| v.set_show_adjacent_month_names(show))
+    }
+
+    /// Show the day of the year instead of the day of the month in the
+    /// `ViewMode::Month` view mode.
+    ///
+    /// Since the day of the year can have up to three digits, this widens the
+    /// cells of the month grid by one column.
+    pub fn set_show_day_of_year(&mut self, show: bool) {
+        self.show_day_of_year = show;
+        self.invalidate();
+    }
+
+    /// Show the day of the year instead of the day of the month in the
+    /// `ViewMode::Month` view mode.
+    ///
+    /// Chainable variant.
+    pub fn show_day_of_year(self, show: bool) -> Self {
+        self.with(|v| v.set_show_day_of_year(show))
+    }
+
+    /// Enable or disable always drawing six weeks in the `ViewMode::Month`
+    /// view mode.
+    ///
+    /// When disabled, only the weeks containing days of the currently viewed
+    /// month are drawn and the height of the view changes from month to
+    /// month. Defaults to `true`.
+    pub fn set_fixed_height(&mut self, fixed: bool) {
+        self.fixed_height = fixed;
+        self.invalidate();
+    }
+
+    /// Enable or disable always drawing six weeks in the `ViewMode::Month`
+    /// view mode.
+    ///
+    /// Chainable variant.
+    pub fn fixed_height(self, fixed: bool) -> Self {
+        self.with(|v| v.set_fixed_height(fixed))
+    }
+
+    /// Keeps the week containing `date` on a fixed row of the
+    /// `ViewMode::Month` grid, e.g. for continuously scrolling calendars.
+    ///
+    /// The grid is shifted vertically so the week lands on the row set via
+    /// [`set_anchor_row`](#method.set_anchor_row), with the weeks above
+    /// filled by days of adjacent months. Six weeks are always drawn while
+    /// anchored.
+    ///
+    /// The anchor is ignored whenever the shifted grid would not contain
+    /// the cursor.
+    pub fn set_anchor_week(&mut self, date: Date<T>) {
+        self.anchor_week = Some(date);
+        self.invalidate();
+    }
+
+    /// Keeps the week containing `date` on a fixed row of the
+    /// `ViewMode::Month` grid.
+    ///
+    /// Chainable variant.
+    pub fn anchor_week(self, date: Date<T>) -> Self {
+        self.with(|v| v.set_anchor_week(date))
+    }
+
+    /// Reverts to the regular layout of the `ViewMode::Month` grid after a
+    /// previous call to [`set_anchor_week`](#method.set_anchor_week).
+    pub fn clear_anchor_week(&mut self) {
+        self.anchor_week = None;
+        self.invalidate();
+    }
+
+    /// Sets the 0-based row of the `ViewMode::Month` grid the anchored week
+    /// is drawn on. Values beyond the last row are clamped. Defaults to `0`.
+    pub fn set_anchor_row(&mut self, row: usize) {
+        self.anchor_row = cmp::min(row, 5);
+    }
+
+    /// Sets the 0-based row of the `ViewMode::Month` grid the anchored week
+    /// is drawn on.
+    ///
+    /// Chainable variant.
+    pub fn anchor_row(self, row: usize) -> Self {
+        self.with(|v| v.set_anchor_row(row))
+    }
+
+    /// Sets the number of years grouped together in the `ViewMode::Decade`
+    /// view mode, e.g. `12` for a zodiac cycle. Defaults to `10`.
+    ///
+    /// Groups start at multiples of `years`. A size of `0` is treated as `1`.
+    pub fn set_decade_size(&mut self, years: u32) {
+        self.decade_size = cmp::max(years, 1);
+        self.invalidate();
+    }
+
+    /// Sets the number of years grouped together in the `ViewMode::Decade`
+    /// view mode.
+    ///
+    /// Chainable variant.
+    pub fn decade_size(self, years: u32) -> Self {
+        self.with(|v| v.set_decade_size(years))
+    }
+
+    /// Show or hide the days of the previous and next month in the
+    /// `ViewMode::Month` view mode.
+    ///
+    /// Hidden days are drawn as blank cells and cannot be clicked.
+    pub fn set_show_adjacent_days(&mut self, show: bool) {
+        self.show_adjacent_days = show;
+    }
+
+    /// Show or hide the days of the previous and next month in the
+    /// `ViewMode::Month` view mode.
+    ///
+    /// Chainable variant.
+    pub fn show_adjacent_days(self, show: bool) -> Self {
+        self.with(|v| v.set_show_adjacent_days(show))
+    }
+
+    /// Enable or disable right-to-left rendering for RTL locales.
+    ///
+    /// Mirrors the column order of all view modes, while keeping the week
+    /// start day. ISO week numbers are drawn right of the grid.
+    pub fn set_rtl(&mut self, rtl: bool) {
+        self.rtl = rtl;
+    }
+
+    /// Enable or disable right-to-left rendering for RTL locales.
+    ///
+    /// Chainable variant.
+    pub fn rtl(self, rtl: bool) -> Self {
+        self.with(|v| v.set_rtl(rtl))
+    }
+
+    /// Enable or disable rendering the available days of the current month in
+    /// bold in the `ViewMode::Month` view mode.
+    ///
+    /// Useful when only a few days of a month can be selected. The cursor and
+    /// the selected date keep their regular highlight.
+    pub fn set_emphasize_available(&mut self, emphasize: bool) {
+        self.emphasize_available = emphasize;
+    }
+
+    /// Enable or disable rendering the available days of the current month in
+    /// bold in the `ViewMode::Month` view mode.
+    ///
+    /// Chainable variant.
+    pub fn emphasize_available(self, emphasize: bool) -> Self {
+        self.with(|v| v.set_emphasize_available(emphasize))
+    }
+
+    /// Enable or disable textual markers around the cursor and the selected
+    /// date.
+    ///
+    /// When enabled, the cursor is wrapped in `>` `<` and the selected date in
+    /// `[` `]`, which keeps both legible on terminals without color support.
+    ///
+    /// If the cursor lies right next to the selected date, the cursor's marker
+    /// takes the column shared by both.
+    pub fn set_monochrome_markers(&mut self, markers: bool) {
+        self.monochrome_markers = markers;
+        self.invalidate();
+    }
+
+    /// Enable or disable textual markers around the cursor and the selected
+    /// date.
+    ///
+    /// Chainable variant.
+    pub fn monochrome_markers(self, markers: bool) -> Self {
+        self.with(|v| v.set_monochrome_markers(markers))
+    }
+
+    /// Show or hide a footer line below the `ViewMode::Month` grid which
+    /// explains why the day under the cursor cannot be selected.
+    ///
+    /// See [`set_disabled_reason`](#method.set_disabled_reason).
+    pub fn set_show_disabled_reason(&mut self, show: bool) {
+        self.show_disabled_reason = show;
+        self.invalidate();
+    }
+
+    /// Show or hide a footer line below the `ViewMode::Month` grid which
+    /// explains why the day under the cursor cannot be selected.
+    ///
+    /// Chainable variant.
+    pub fn show_disabled_reason(self, show: bool) -> Self {
+        self.with(|v| v.set_show_disabled_reason(show))
+    }
+
+    /// Show or hide a footer line below the `ViewMode::Month` grid which
+    /// shows the position of the selected date within its year, e.g.
+    /// `Day 207 of 365, 158 remaining`.
+    ///
+    /// The text is provided by [`Locale::day_of_year_label`](trait.Locale.html#method.day_of_year_label).
+    pub fn set_show_day_of_year_footer(&mut self, show: bool) {
+        self.show_day_of_year_footer = show;
+        self.invalidate();
+    }
+
+    /// Show or hide a footer line below the `ViewMode::Month` grid which
+    /// shows the position of the selected date within its year.
+    ///
+    /// Chainable variant.
+    pub fn show_day_of_year_footer(self, show: bool) -> Self {
+        self.with(|v| v.set_show_day_of_year_footer(show))
+    }
+
+    /// Confirms the current cursor position the same way pressing `<Enter>`
+    /// does.
+    ///
+    /// Depending on the view mode this either selects the date under the
+    /// cursor or switches to the next lower view mode. Returns the `on_submit`
+    /// callback which should then be run against the `Cursive` root, if any.
+    pub fn commit(&mut self) -> Option<Callback>
+    where
+        T: 'static,
+    {
+        match self.submit() {
+            EventResult::Consumed(cb) if !self.silent => cb,
+            _ => None,
+        }
+    }
+
+    /// Runs `f` against this view with all callbacks suppressed.
+    ///
+    /// Setters like [`set_view_date`](#method.set_view_date) never trigger
+    /// any callbacks on their own. Within `f`, [`commit`](#method.commit)
+    /// and events passed to `on_event` update the view as usual but do not
+    /// produce any `on_submit`, `on_select` or `on_change` callbacks. This
+    /// allows restoring or animating state without causing feedback loops,
+    /// while genuine user events outside of `f` still trigger them.
+    pub fn set_silent<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let silent = self.silent;
+        self.silent = true;
+        f(self);
+        self.silent = silent;
+    }
+
+    /// Sets a callback describing why a date cannot be selected, e.g. because
+    /// it lies in the past or is a holiday.
+    ///
+    /// The callback is only invoked for dates which are not available.
+    pub fn set_disabled_reason<F>(&mut self, cb: F)
+    where
+        F: Fn(&Date<T>) -> Option<String> + Send + Sync + 'static,
+    {
+        self.disabled_reason = Some(Arc::new(cb));
+    }
+
+    /// Sets a callback describing why a date cannot be selected.
+    ///
+    /// Chainable variant.
+    pub fn disabled_reason<F>(self, cb: F) -> Self
+    where
+        F: Fn(&Date<T>) -> Option<String> + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_disabled_reason(cb))
+    }
+
+    /// Sets a callback returning the label drawn for each day of the
+    /// currently viewed month in the `ViewMode::Month` view mode, replacing
+    /// the day number.
+    ///
+    /// Labels are truncated to the cell width, see
+    /// [`set_cell_width`](#method.set_cell_width).
+    pub fn set_day_label<F>(&mut self, cb: F)
+    where
+        F: Fn(&Date<T>) -> String + Send + Sync + 'static,
+    {
+        self.day_label = Some(Arc::new(cb));
+        self.invalidate();
+    }
+
+    /// Sets a callback returning the label drawn for each day of the
+    /// currently viewed month in the `ViewMode::Month` view mode.
+    ///
+    /// Chainable variant.
+    pub fn day_label<F>(self, cb: F) -> Self
+    where
+        F: Fn(&Date<T>) -> String + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_day_label(cb))
+    }
+
+    /// Sets a callback returning the title drawn above the grid of each view
+    /// mode, e.g. `2017年7月` instead of `July 2017`.
+    ///
+    /// The callback receives the current view mode and the visually selected
+    /// date. Returning `None` keeps the default title of the view mode.
+    pub fn set_header_formatter<F>(&mut self, cb: F)
+    where
+        F: Fn(ViewMode, &Date<T>) -> Option<String> + Send + Sync + 'static,
+    {
+        self.header_formatter = Some(Arc::new(cb));
+        self.invalidate();
+    }
+
+    /// Sets a callback returning the title drawn above the grid of each view
+    /// mode.
+    ///
+    /// Chainable variant.
+    pub fn header_formatter<F>(self, cb: F) -> Self
+    where
+        F: Fn(ViewMode, &Date<T>) -> Option<String> + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_header_formatter(cb))
+    }
+
+    /// Sets the minimum number of characters available for each day label in
+    /// the `ViewMode::Month` view mode.
+    ///
+    /// Cells are never narrower than required by the day numbers.
+    pub fn set_cell_width(&mut self, width: usize) {
+        self.cell_width = width;
+        self.invalidate();
+    }
+
+    /// Sets the minimum number of characters available for each day label in
+    /// the `ViewMode::Month` view mode.
+    ///
+    /// Chainable variant.
+    pub fn cell_width(self, width: usize) -> Self {
+        self.with(|v| v.set_cell_width(width))
+    }
+
+    /// Returns the dates marked as having events.
+    pub fn get_event_dates(&self) -> &HashSet<Date<T>> {
+        &self.event_dates
+    }
+
+    /// Returns the dates marked as having events in ascending order, e.g. to
+    /// render a legend.
+    pub fn marked_dates(&self) -> Vec<Date<T>> {
+        let mut dates: Vec<Date<T>> = self.event_dates.iter().cloned().collect();
+        dates.sort();
+        dates
+    }
+
+    /// Sets the dates which are marked as having events in the
+    /// `ViewMode::Month` view mode.
+    ///
+    /// The marker replaces the padding in front of the day label, so days
+    /// whose label fills the whole cell are only marked once the cells are
+    /// widened via [`set_cell_width`](#method.set_cell_width).
+    pub fn set_event_dates(&mut self, dates: HashSet<Date<T>>) {
+        self.event_dates = dates;
+    }
+
+    /// Sets the dates which are marked as having events in the
+    /// `ViewMode::Month` view mode.
+    ///
+    /// Chainable variant.
+    pub fn event_dates(self, dates: HashSet<Date<T>>) -> Self {
+        self.with(|v| v.set_event_dates(dates))
+    }
+
+    /// Sets the character used to mark dates having events. Defaults to `•`.
+    pub fn set_event_marker(&mut self, marker: char) {
+        self.event_marker = marker;
+    }
+
+    /// Sets the character used to mark dates having events.
+    ///
+    /// Chainable variant.
+    pub fn event_marker(self, marker: char) -> Self {
+        self.with(|v| v.set_event_marker(marker))
+    }
+
+    /// Sets the number of characters used for the week day headers in the
+    /// `ViewMode::Month` view mode.
+    ///
+    /// `1` uses the narrow form, e.g. `M`, `2` the short form, e.g. `Mo`,
+    /// and `3` the first three characters of the long form, e.g. `Mon`.
+    /// Defaults to `2`.
+    ///
+    /// Widths outside of `1..=3` are clamped into that range.
+    pub fn set_weekday_header_width(&mut self, width: usize) {
+        self.weekday_header_width = width.clamp(1, 3);
+        self.invalidate();
+    }
+
+    /// Sets the number of characters used for the week day headers in the
+    /// `ViewMode::Month` view mode.
+    ///
+    /// Chainable variant.
+    pub fn weekday_header_width(self, width: usize) -> Self {
+        self.with(|v| v.set_weekday_header_width(width))
+    }
+
+    /// Sets a callback returning an optional color for each day of the
+    /// currently viewed month in the `ViewMode::Month` view mode, e.g. to
+    /// color holidays or days with events.
+    ///
+    /// The callback is only invoked for available dates and the returned
+    /// color never overrides the cursor or selection highlight.
+    pub fn set_day_decorator<F>(&mut self, cb: F)
+    where
+        F: Fn(&Date<T>) -> Option<ColorStyle> + Send + Sync + 'static,
+    {
+        self.day_decorator = Some(Arc::new(cb));
+    }
+
+    /// Sets a callback returning an optional color for each day of the
+    /// currently viewed month in the `ViewMode::Month` view mode.
+    ///
+    /// Chainable variant.
+    pub fn day_decorator<F>(self, cb: F) -> Self
+    where
+        F: Fn(&Date<T>) -> Option<ColorStyle> + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_day_decorator(cb))
+    }
+
+    /// Sets a callback returning an intensity between `0` and `255` for each
+    /// day of the currently viewed month, which is used to tint the
+    /// background of the day's cell in the `ViewMode::Month` view mode.
+    ///
+    /// An intensity of `0` leaves the cell untinted, all others are mapped
+    /// evenly onto the colors of the
+    /// [heatmap palette](#method.set_heatmap_palette). The cursor and the
+    /// selected date are never tinted.
+    pub fn set_heatmap<F>(&mut self, cb: F)
+    where
+        F: Fn(&Date<T>) -> u8 + Send + Sync + 'static,
+    {
+        self.heatmap = Some(Arc::new(cb));
+    }
+
+    /// Sets a callback returning an intensity between `0` and `255` for each
+    /// day of the currently viewed month.
+    ///
+    /// Chainable variant.
+    pub fn heatmap<F>(self, cb: F) -> Self
+    where
+        F: Fn(&Date<T>) -> u8 + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_heatmap(cb))
+    }
+
+    /// Sets the background colors used for the heatmap, ordered from the
+    /// lowest to the highest intensity.
+    ///
+    /// The default palette consists of four shades of green.
+    pub fn set_heatmap_palette(&mut self, palette: Vec<Color>) {
+        self.heatmap_palette = palette;
+    }
+
+    /// Sets the background colors used for the heatmap, ordered from the
+    /// lowest to the highest intensity.
+    ///
+    /// Chainable variant.
+    pub fn heatmap_palette(self, palette: Vec<Color>) -> Self {
+        self.with(|v| v.set_heatmap_palette(palette))
+    }
+
+    /// Sets a callback which is called with all selected dates, in ascending
+    /// order, whenever a date is toggled in multi select mode.
+    pub fn set_on_multi_change<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &[Date<T>]) + Send + Sync + 'static,
+    {
+        self.on_multi_change = Some(Arc::new(move |s, dates| cb(s, dates)));
+    }
+
+    /// Sets a callback which is called with all selected dates whenever a
+    /// date is toggled in multi select mode.
+    ///
+    /// Chainable variant.
+    pub fn on_multi_change<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &[Date<T>]) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_multi_change(cb))
+    }
+
+    /// Sets a callback which is called with the start and end of the range
+    /// once it has been selected in range select mode.
+    pub fn set_on_range_submit<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &Date<T>, &Date<T>) + Send + Sync + 'static,
+    {
+        self.on_range_submit = Some(Arc::new(move |s, start, end| cb(s, start, end)));
+    }
+
+    /// Sets a callback which is called with the start and end of the range
+    /// once it has been selected in range select mode.
+    ///
+    /// Chainable variant.
+    pub fn on_range_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &Date<T>, &Date<T>) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_range_submit(cb))
+    }
+
+    /// Sets a callback which is called when `<Backspace>` is pressed while
+    /// the view already is in its highest view mode, e.g. to show a hint
+    /// that only days can be selected.
+    ///
+    /// `<Enter>` is never blocked, since it submits the cursor position in
+    /// the lowest view mode.
+    pub fn set_on_mode_locked<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive) + Send + Sync + 'static,
+    {
+        self.on_mode_locked = Some(Callback::from_fn(cb));
+    }
+
+    /// Sets a callback which is called when `<Backspace>` is pressed while
+    /// the view already is in its highest view mode.
+    ///
+    /// Chainable variant.
+    pub fn on_mode_locked<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_mode_locked(cb))
+    }
+
+    /// Sets a callback which is called when the view gains focus.
+    pub fn set_on_focus<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive) + Send + Sync + 'static,
+    {
+        self.on_focus = Some(Callback::from_fn(cb));
+    }
+
+    /// Sets a callback which is called when the view gains focus.
+    ///
+    /// Chainable variant.
+    pub fn on_focus<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_focus(cb))
+    }
+
+    /// Sets a callback which is called when the view loses focus.
+    pub fn set_on_blur<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive) + Send + Sync + 'static,
+    {
+        self.on_blur = Some(Callback::from_fn(cb));
+    }
+
+    /// Sets a callback which is called when the view loses focus.
+    ///
+    /// Chainable variant.
+    pub fn on_blur<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_blur(cb))
+    }
+
+    /// Sets a callback which is called when the cursor is clamped to the
+    /// earliest or latest selectable date because a keyboard or mouse
+    /// navigation would move it past that limit.
+    pub fn set_on_boundary<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, Boundary) + Send + Sync + 'static,
+    {
+        self.on_boundary = Some(Arc::new(move |s, boundary| cb(s, boundary)));
+    }
+
+    /// Sets a callback which is called when the cursor is clamped to the
+    /// earliest or latest selectable date.
+    ///
+    /// Chainable variant.
+    pub fn on_boundary<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, Boundary) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_boundary(cb))
+    }
+
+    /// Sets a callback to be used when `<Enter>` is pressed to select a date.
+    pub fn set_on_submit<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &Date<T>) + Send + Sync + 'static,
+    {
+        self.on_submit = Some(Arc::new(move |s, date| cb(s, date)));
+    }
+
+    /// Sets a callback to be used when `<Enter>` is pressed to select a date.
+    ///
+    /// Chainable variant.
+    pub fn on_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &Date<T>) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_submit(cb))
+    }
+
+    /// Sets a callback to be used when an a new date is visually selected.
+    pub fn set_on_select<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &Date<T>) + Send + Sync + 'static,
+    {
+        self.on_select = Some(Arc::new(move |s, date| cb(s, date)));
+    }
+
+    /// Sets a callback to be used when an a new date is visually selected.
+    ///
+    /// Chainable variant.
+    pub fn on_select<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &Date<T>) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_select(cb))
+    }
+
+    /// Sets a callback to be used whenever the selected date, the visually
+    /// selected date or the view mode changed in response to an event.
+    ///
+    /// Multiple changes caused by a single event result in only one call,
+    /// which receives the state after all changes were applied.
+    pub fn set_on_change<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &CalendarState<T>) + Send + Sync + 'static,
+    {
+        self.on_change = Some(Arc::new(move |s, state| cb(s, state)));
+    }
+
+    /// Sets a callback to be used whenever the selected date, the visually
+    /// selected date or the view mode changed in response to an event.
+    ///
+    /// Chainable variant.
+    pub fn on_change<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &CalendarState<T>) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_change(cb))
+    }
+
+    /// Sets a callback which is called with the new view mode whenever the
+    /// view mode changed in response to an event, e.g. when drilling down
+    /// via `<Enter>` or a mouse click, or when moving up via `<Backspace>`.
+    pub fn set_on_view_mode_change<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, ViewMode) + Send + Sync + 'static,
+    {
+        self.on_view_mode_change = Some(Arc::new(move |s, mode| cb(s, mode)));
+    }
+
+    /// Sets a callback which is called with the new view mode whenever the
+    /// view mode changed in response to an event.
+    ///
+    /// Chainable variant.
+    pub fn on_view_mode_change<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, ViewMode) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_view_mode_change(cb))
+    }
+}
+
+impl<T: TimeZone + Send + Sync, L: Locale + Send + Sync + 'static> CalendarView<T, L>
+where
+    T::Offset: Send + Sync,
+{
+    fn draw_view_mode(&self, printer: &Printer<'_, '_>) {
+        match self.view_mode {
+            ViewMode::Week | ViewMode::Month => {
+                self.draw_month(printer);
+                self.draw_disabled_reason(printer);
+                self.draw_day_of_year_footer(printer);
+            }
+            ViewMode::Year => self.draw_year(printer),
+            ViewMode::Decade => self.draw_decade(printer),
+        }
+    }
+
+    fn draw_disabled_reason(&self, printer: &Printer<'_, '_>) {
+        if !self.show_disabled_reason {
+            return;
+        }
+        if let Some(reason) = self.get_disabled_reason(&self.view_date) {
+            printer.with_color(self.style.unavailable, |printer| {
+                printer.print(
+                    (0, 2 + self.grid_rows()),
+                    &format!("{:^width$}", reason, width = self.size.x),
+                );
+            });
+        }
+    }
+
+    fn draw_day_of_year_footer(&self, printer: &Printer<'_, '_>) {
+        if !self.show_day_of_year_footer {
+            return;
+        }
+        let y = 2 + self.grid_rows() + if self.show_disabled_reason { 1 } else { 0 };
+        printer.with_color(self.style.header, |printer| {
+            printer.print(
+                (0, y),
+                &format!("{:^width$}", self.day_of_year_label(), width = self.size.x),
+            );
+        });
+    }
+
+    /// Footer text describing the position of the selected date within its
+    /// year.
+    fn day_of_year_label(&self) -> String {
+        let days_in_year = NaiveDate::from_ymd_opt(self.date.year(), 12, 31)
+            .map(|date| date.ordinal())
+            .unwrap_or(365);
+        self.runtime_locale()
+            .day_of_year_label(self.date.ordinal(), days_in_year)
+    }
+
+    fn draw_month(&self, printer: &Printer<'_, '_>) {
+        let year = self.view_date.year();
+        let month: Month = self.view_date.month0().into();
+
+        let view_day = self.view_date.day0() as i32;
+
+        let month_days = month.number_of_days(year);
+        let prev_month_days = month.prev_number_of_days(year);
+
+        // Draw Month Name
+        printer.with_color(self.style.header, |printer| {
+            printer.print(
+                (0, 0),
+                &format!("{:^width$}", self.header_title(), width = self.size.x),
+            );
+        });
+
+        // Draw Adjacent Month Names
+        if let Some((prev, next)) = self.adjacent_month_labels() {
+            printer.with_color(self.style.adjacent, |printer| {
+                printer.print((0, 0), &prev);
+                printer.print((self.size.x - next.chars().count(), 0), &next);
+            });
+        }
+
+        // Draw Weekdays
+        let h_offset = self.h_offset(ViewMode::Month);
+        let cell_width = self.grid_cell_width();
+        let w_offset: i32 = self.week_start.into();
+        for i in 0..7 {
+            let week_day: WeekDay = (i + w_offset).into();
+            printer.with_color(self.style.header, |printer| {
+                printer.print(
+                    (
+                        h_offset + self.grid_column(i as usize, 7) as i32 * cell_width,
+                        1,
+                    ),
+                    &format!(
+                        "{:>width$}",
+                        self.week_day_header(week_day),
+                        width = cell_width as usize - 1
+                    ),
+                );
+            });
+        }
+
+        // Draw days
+        let d_offset = self.month_day_offset();
+        let mut markers = Vec::new();
+
+        let cells = 7 * self.grid_rows() as i32;
+        for (index, i) in (-d_offset..-d_offset + cells).enumerate() {
+            let (day_number, month_offset) = if i < 0 {
+                (prev_month_days + i, -1)
+            } else if i > month_days - 1 {
+                (i - month_days, 1)
+            } else {
+                (i, 0)
+            };
+
+            if let Some(exact_date) =
+                date_from_day_and_offsets(&self.view_date, Some(day_number), 0, month_offset, 0)
+            {
+                let mut color = self.cell_color(&exact_date, printer.focused);
+                if month_offset == 0 && exact_date != self.view_date && exact_date != self.date {
+                    if let Some(back) = self.heatmap_color(&exact_date) {
+                        color.back = back.into();
+                    }
+                }
+
+                let (x, y) = (
+                    h_offset + self.grid_column(index % 7, 7) as i32 * cell_width,
+                    2 + (index as i32 / 7),
+                );
+
+                // Draw ISO Weeks (Only makes sense when start_of_week is Monday)
+                if self.show_iso_weeks
+                    && index as i32 % 7 == 0
+                    && (self.show_adjacent_days || index / 7 < self.week_row_count())
+                {
+                    let week_number = self.row_week_number(index / 7);
+                    printer.with_color(self.style.iso_week, |printer| {
+                        if let Some(week_number) = week_number {
+                            printer.print((self.iso_week_x(), y), &format!("{:>2}", week_number));
+                        }
+                    });
+                }
+
+                if month_offset != 0 && !self.show_adjacent_days {
+                    continue;
+                }
+
+                // Draw day number
+                let label = match self.day_label {
+                    Some(ref cb) if month_offset == 0 => cb(&exact_date)
+                        .chars()
+                        .take(cell_width as usize - 1)
+                        .collect(),
+                    _ if self.show_day_of_year => exact_date.ordinal().to_string(),
+                    _ => exact_date.day().to_string(),
+                };
+                let effect = self.cell_effect(&exact_date);
+                printer.with_color(color, |printer| {
+                    printer.with_effect(effect, |printer| {
+                        printer.print(
+                            (x, y),
+                            &self.cell_label(&exact_date, &label, cell_width as usize - 1),
+                        );
+                    });
+                });
+                markers.push((
+                    (x, y),
+                    cell_width - 1,
+                    month_offset == 0 && view_day == i,
+                    self.date_selected(&exact_date),
+                ));
+            }
+        }
+        self.draw_markers(printer, &markers);
+    }
+
+    fn draw_year(&self, printer: &Printer<'_, '_>) {
+        let active_month = self.date.month0();
+        let view_month = self.view_date.month0();
+        let year = self.view_date.year();
+        let d_year = self.date.year() - year;
+
+        // Draw Year
+        printer.with_color(self.style.header, |printer| {
+            printer.print(
+                (0, 0),
+                &format!("{:^width$}", self.header_title(), width = self.size.x),
+            );
+        });
+
+        // Draw Month Names
+        let h_offset = self.h_offset(ViewMode::Year);
+        let cell_width = self.year_cell_width();
+        let mut markers = Vec::new();
+        for i in 0..12 {
+            let color = if !self.month_available(i, year) {
+                self.style.unavailable
+            } else if view_month == i {
+                if self.enabled && printer.focused {
+                    self.style.cursor
+                } else {
+                    self.style.selected
+                }
+            } else if active_month == i && d_year == 0 {
+                if self.enabled && printer.focused {
+                    self.style.selected
+                } else {
+                    self.style.normal
+                }
+            } else if self.today.month0() == i && self.today.year() == year {
+                self.style.today
+            } else {
+                self.style.normal
+            };
+
+            let (x, y) = (
+                h_offset + self.grid_column(i as usize % 4, 4) as i32 * cell_width,
+                2 + (i as i32 / 4) * 2,
+            );
+            let color = self.disabled_color(
+                color,
+                self.month_available(i, year),
+                active_month == i && d_year == 0,
+            );
+            printer.with_color(color, |printer| {
+                printer.print(
+                    (x, y),
+                    &format!(
+                        "{:>width$}",
+                        self.runtime_locale().month(i.into(), false),
+                        width = cell_width as usize - 1
+                    ),
+                );
+            });
+            markers.push((
+                (x, y),
+                cell_width - 1,
+                view_month == i,
+                active_month == i && d_year == 0,
+            ));
+        }
+        self.draw_markers(printer, &markers);
+    }
+
+    fn draw_decade(&self, printer: &Printer<'_, '_>) {
+        let active_year = self.date.year();
+        let view_year = self.view_date.year();
+        let decade = self.decade_start();
+        let years = 0..self.decade_size as i32;
+
+        // Draw Year Range
+        let header = format!("{:^width$}", self.header_title(), width = self.size.x);
+        printer.with_color(self.decade_header_color(), |printer| {
+            printer.print((0, 0), &header);
+        });
+
+        // Draw Years
+        let h_offset = self.h_offset(ViewMode::Decade);
+        let mut markers = Vec::new();
+        for (index, i) in (-1..years.end + 2).enumerate() {
+            let year = decade + i;
+            let color = if !self.year_available(year) {
+                self.style.unavailable
+            } else if !years.contains(&i) {
+                if active_year == year {
+                    if self.enabled && printer.focused {
+                        self.style.selected
+                    } else {
+                        self.style.adjacent
+                    }
+                } else {
+                    self.style.adjacent
+                }
+            } else if view_year == year {
+                if self.enabled && printer.focused {
+                    self.style.cursor
+                } else {
+                    self.style.selected
+                }
+            } else if active_year == year {
+                if self.enabled {
+                    self.style.selected
+                } else {
+                    self.style.normal
+                }
+            } else if self.today.year() == year {
+                self.style.today
+            } else {
+                self.style.normal
+            };
+
+            let (x, y) = (
+                h_offset + self.grid_column(index % 4, 4) as i32 * 5,
+                2 + (index as i32 / 4) * 2,
+            );
+
+            let color = self.disabled_color(color, self.year_available(year), active_year == year);
+            printer.with_color(color, |printer| {
+                printer.print(
+                    (x, y),
+                    &format!("{:>4}", year_cell_label(self.runtime_locale(), year)),
+                );
+            });
+            markers.push((
+                (x, y),
+                4,
+                years.contains(&i) && view_year == year,
+                active_year == year,
+            ));
+        }
+        self.draw_markers(printer, &markers);
+    }
+
+    /// Title drawn above the grid of the current view mode.
+    fn header_title(&self) -> String {
+        if let Some(ref cb) = self.header_formatter {
+            if let Some(title) = cb(self.view_mode, &self.view_date) {
+                return title;
+            }
+        }
+
+        let locale = self.runtime_locale();
+        match self.view_mode {
+            ViewMode::Week | ViewMode::Month => self.month_title(),
+            ViewMode::Year => locale.era_label(self.view_date.year()),
+            ViewMode::Decade => {
+                let decade = self.decade_start();
+                format!(
+                    "{} - {}",
+                    locale.era_label(decade),
+                    locale.era_label(decade + self.decade_size as i32 - 1)
+                )
+            }
+        }
+    }
+
+    fn month_title(&self) -> String {
+        let month: Month = self.view_date.month0().into();
+        format!(
+            "{} {}",
+            self.runtime_locale().month(month, true),
+            self.runtime_locale().era_label(self.view_date.year())
+        )
+    }
+
+    /// Labels for the previous and next month, if they fit into the header
+    /// next to the centered month name.
+    fn adjacent_month_labels(&self) -> Option<(String, String)> {
+        if !self.show_adjacent_month_names {
+            return None;
+        }
+
+        let month: Month = self.view_date.month0().into();
+        let prev = format!(
+            "\u{2039}{}",
+            self.runtime_locale().month(month.prev(), false)
+        );
+        let next = format!(
+            "{}\u{203a}",
+            self.runtime_locale().month(month.succ(), false)
+        );
+
+        let title = self.header_title();
+        let free = self.size.x.saturating_sub(title.chars().count());
+        let (left, right) = (free / 2, free - free / 2);
+
+        if prev.chars().count() < left && next.chars().count() < right {
+            Some((prev, next))
+        } else {
+            None
+        }
+    }
+
+    /// Month offset for a click onto one of the adjacent month names in the
+    /// header.
+    fn header_month_offset(&self, x: usize) -> Option<i32> {
+        let (prev, next) = self.adjacent_month_labels()?;
+        if x < prev.chars().count() {
+            Some(-1)
+        } else if x >= self.size.x - next.chars().count() && x < self.size.x {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    /// Number of leading cells in the `ViewMode::Month` grid which show days
+    /// of the previous months.
+    ///
+    /// Negative if the grid is anchored on a later week and the first days
+    /// of the month are scrolled out of view.
+    fn month_day_offset(&self) -> i32 {
+        if let Some(offset) = self.anchored_day_offset() {
+            return offset;
+        }
+
+        let month_start = self.view_date.with_day0(0).unwrap();
+        let first_week_day: WeekDay = (month_start.weekday() as i32).into();
+        let w_offset: i32 = self.week_start.into();
+        let d_shift = ((WeekDay::Monday as i32 - w_offset) + 7) % 7;
+        ((first_week_day as i32) + d_shift) % 7
+    }
+
+    /// The first valid local time of the currently selected date.
+    fn selected_day_start(&self) -> Option<DateTime<T>> {
+        let tz = self.date.timezone();
+        let day = self.date.naive_local();
+        (0..24 * 60).find_map(|minute| {
+            let time = NaiveTime::from_hms_opt(minute / 60, minute % 60, 0)?;
+            tz.from_local_datetime(&day.and_time(time)).earliest()
+        })
+    }
+
+    /// Number of leading cells in the `ViewMode::Month` grid required to
+    /// draw the anchored week on its configured row.
+    ///
+    /// Returns `None` without an anchor or if the cursor would be scrolled
+    /// out of the grid.
+    fn anchored_day_offset(&self) -> Option<i32> {
+        let anchor = self.week_start_of(self.anchor_week.as_ref()?)?;
+        let month_start = self.view_date.with_day0(0)?;
+        let offset = month_start.signed_duration_since(anchor).num_days() as i32
+            + 7 * self.anchor_row as i32;
+
+        let index = offset + self.view_date.day0() as i32;
+        if (0..42).contains(&index) {
+            Some(offset)
+        } else {
+            None
+        }
+    }
+
+    /// First day of the week containing the given date, according to the
+    /// configured week start day.
+    fn week_start_of(&self, date: &Date<T>) -> Option<Date<T>> {
+        let w_offset: i32 = self.week_start.into();
+        let week_day = date.weekday() as i32;
+        date_from_day_and_offsets(date, None, -((week_day - w_offset + 7) % 7), 0, 0)
+    }
+
+    fn same_week(&self, a: &Date<T>, b: &Date<T>) -> bool {
+        self.week_start_of(a) == self.week_start_of(b)
+    }
+
+    /// The date shown in the first cell of the `ViewMode::Month` grid.
+    fn month_grid_start(&self) -> Option<Date<T>> {
+        let month_start = self.view_date.with_day0(0)?;
+        date_from_day_and_offsets(&month_start, None, -self.month_day_offset(), 0, 0)
+    }
+
+    /// ISO week number of the first day in the given row of the
+    /// `ViewMode::Month` grid.
+    fn row_iso_week(&self, row: usize) -> Option<u32> {
+        let row_start =
+            date_from_day_and_offsets(&self.month_grid_start()?, None, row as i32 * 7, 0, 0)?;
+
+        // Every row contains exactly one Thursday, which determines the ISO
+        // week of the majority of its days
+        let thursday = (Weekday::Thu.num_days_from_monday() + 7
+            - row_start.weekday().num_days_from_monday())
+            % 7;
+        let thursday = date_from_day_and_offsets(&row_start, None, thursday as i32, 0, 0)?;
+        Some(thursday.iso_week().week())
+    }
+
+    /// Week of the year of the given row of the `ViewMode::Month` grid,
+    /// counting the week which contains January 1st as week `1`.
+    fn row_us_week(&self, row: usize) -> Option<u32> {
+        let row_start =
+            date_from_day_and_offsets(&self.month_grid_start()?, None, row as i32 * 7, 0, 0)?;
+        let row_end = date_from_day_and_offsets(&row_start, None, 6, 0, 0)?;
+        let first_week = self.week_start_of(&row_end.with_ordinal(1)?)?;
+        Some((row_start.signed_duration_since(first_week).num_days() / 7) as u32 + 1)
+    }
+
+    /// Number shown in the week column for the given row of the
+    /// `ViewMode::Month` grid, honoring a configured week number base.
+    fn row_week_number(&self, row: usize) -> Option<i32> {
+        match self.week_number_base {
+            Some((ref anchor, start_number)) => {
+                let row_start = date_from_day_and_offsets(
+                    &self.month_grid_start()?,
+                    None,
+                    row as i32 * 7,
+                    0,
+                    0,
+                )?;
+                let anchor_week = self.week_start_of(anchor)?;
+                let weeks = row_start.signed_duration_since(anchor_week).num_days() / 7;
+                let number = i64::from(start_number) + weeks;
+                if weeks < 0 || number < 0 {
+                    None
+                } else {
+                    Some(number as i32)
+                }
+            }
+            None => match self.week_numbering {
+                WeekNumbering::None => None,
+                WeekNumbering::Iso => Some(self.row_iso_week(row).unwrap_or(0) as i32),
+                WeekNumbering::UsWeekOfYear => Some(self.row_us_week(row).unwrap_or(0) as i32),
+            },
+        }
+    }
+
+    /// Heatmap background color of the given date, if any.
+    fn heatmap_color(&self, date: &Date<T>) -> Option<Color> {
+        let value = self.heatmap.as_ref().map_or(0, |cb| cb(date)) as usize;
+        if value == 0 || self.heatmap_palette.is_empty() {
+            None
+        } else {
+            let bucket = (value - 1) * self.heatmap_palette.len() / 255;
+            Some(self.heatmap_palette[bucket])
+        }
+    }
+
+    /// Routes a cell color through the disabled palette while this view is
+    /// disabled, keeping unavailable cells and the selected date distinct.
+    fn disabled_color(&self, color: ColorStyle, available: bool, selected: bool) -> ColorStyle {
+        if self.enabled || !available {
+            color
+        } else if selected {
+            self.style.selected
+        } else {
+            self.style.disabled
+        }
+    }
+
+    fn draw_markers(&self, printer: &Printer<'_, '_>, cells: &[MarkerCell]) {
+        for (position, marker) in self.marker_glyphs(cells) {
+            printer.print(position, marker);
+        }
+    }
+
+    /// Monochrome markers for the given grid cells.
+    ///
+    /// Adjacent cells share the column between them, so the cursor's
+    /// markers are placed last and replace a neighbouring selection marker.
+    fn marker_glyphs(&self, cells: &[MarkerCell]) -> Vec<((i32, i32), &'static str)> {
+        let mut glyphs: Vec<((i32, i32), &'static str)> = Vec::new();
+        if !self.monochrome_markers {
+            return glyphs;
+        }
+
+        let selected = cells
+            .iter()
+            .filter(|cell| cell.3 && !cell.2)
+            .map(|&(position, width, _, _)| (position, width, ("[", "]")));
+        let cursor = cells
+            .iter()
+            .filter(|cell| cell.2)
+            .map(|&(position, width, _, _)| (position, width, (">", "<")));
+
+        for ((x, y), width, (left, right)) in selected.chain(cursor) {
+            for (position, marker) in [((x - 1, y), left), ((x + width, y), right)] {
+                glyphs.retain(|&(p, _)| p != position);
+                glyphs.push((position, marker));
+            }
+        }
+        glyphs
+    }
+
+    /// Offset of the calendar content within the given available space.
+    fn content_offset(&self, available: Vec2) -> Vec2 {
+        if self.center_in_parent {
+            Vec2::new(
+                HAlign::Center.get_offset(self.size.x, available.x),
+                VAlign::Center.get_offset(self.size.y, available.y),
+            )
+        } else {
+            Vec2::new(0, self.v_align.get_offset(self.size.y, available.y))
+        }
+    }
+
+    /// Text effect of a day in the `ViewMode::Month` grid.
+    fn cell_effect(&self, date: &Date<T>) -> Effect {
+        if self.emphasize_available
+            && self.is_current_month(date)
+            && *date != self.view_date
+            && *date != self.date
+            && self.date_available(date)
+        {
+            Effect::Bold
+        } else if *date == self.today {
+            Effect::Underline
+        } else {
+            Effect::Simple
+        }
+    }
+
+    /// Right aligns a day label within its cell, replacing the leading
+    /// padding with the event marker if the date has events.
+    fn cell_label(&self, date: &Date<T>, label: &str, width: usize) -> String {
+        let text = format!("{:>width$}", label, width = width);
+        if self.event_dates.contains(date) && text.starts_with(' ') {
+            format!("{}{}", self.event_marker, &text[1..])
+        } else {
+            text
+        }
+    }
+
+    /// Maps a logical column to the drawn column, mirroring it when
+    /// rendering right-to-left.
+    fn grid_column(&self, column: usize, columns: usize) -> usize {
+        if self.rtl {
+            columns - 1 - column
+        } else {
+            column
+        }
+    }
+
+    /// Number of week rows drawn in the `ViewMode::Month` grid.
+    fn grid_rows(&self) -> usize {
+        if self.fixed_height || self.anchored_day_offset().is_some() {
+            6
+        } else {
+            self.week_row_count()
+        }
+    }
+
+    /// Number of rows required to draw the current view mode.
+    fn content_height(&self) -> usize {
+        let height = match self.view_mode {
+            ViewMode::Week | ViewMode::Month if self.show_day_of_year_footer => {
+                3 + self.grid_rows()
+            }
+            ViewMode::Week | ViewMode::Month => 2 + self.grid_rows(),
+            ViewMode::Year => 8,
+            // Leading year, grouped years and two trailing years in rows of
+            // four
+            ViewMode::Decade => 2 + 2 * (self.decade_size as usize + 3).div_ceil(4),
+        };
+
+        // Leave room for the disabled reason footer
+        if self.show_disabled_reason {
+            height + 1
+        } else {
+            height
+        }
+    }
+
+    /// Horizontal distance between two day columns of the `ViewMode::Month` grid.
+    fn grid_cell_width(&self) -> i32 {
+        let label_width = if self.show_day_of_year { 3 } else { 2 };
+        let header_width = self.weekday_header_width as i32;
+        cmp::max(cmp::max(label_width, header_width), self.cell_width as i32) + 1
+    }
+
+    /// Horizontal distance between two month columns of the `ViewMode::Year`
+    /// grid, wide enough for the longest short month name of the locale.
+    fn year_cell_width(&self) -> i32 {
+        let locale = self.runtime_locale();
+        let label_width = (0..12)
+            .map(|month| locale.month(Month::from(month), false).chars().count())
+            .max()
+            .unwrap_or(0);
+        cmp::max(label_width as i32, 4) + 1
+    }
+
+    fn week_day_header(&self, week_day: WeekDay) -> String {
+        let locale = self.runtime_locale();
+        match self.weekday_header_width {
+            1 => locale.week_day_narrow(week_day).to_string(),
+            3 => locale.week_day(week_day, true).chars().take(3).collect(),
+            _ => locale.week_day(week_day, false).to_string(),
+        }
+    }
+
+    fn runtime_locale(&self) -> &dyn RuntimeLocale {
+        match self.locale {
+            Some(ref locale) => locale,
+            None => &StaticLocale::<L>(PhantomData),
+        }
+    }
+
+    fn h_offset(&self, mode: ViewMode) -> i32 {
+        // Reserve a column for the left-hand monochrome marker
+        let marker_offset = if self.monochrome_markers { 1 } else { 0 };
+
+        // The ISO week gutter lies right of the grid in RTL mode
+        if self.rtl {
+            marker_offset
+        } else {
+            self.iso_gutter_width(mode) + marker_offset
+        }
+    }
+
+    /// Width of the ISO week gutter next to the grid of the given view mode,
+    /// including the gap towards the grid.
+    fn iso_gutter_width(&self, mode: ViewMode) -> i32 {
+        if !(self.show_iso_weeks || self.reserve_iso_column) {
+            return 0;
+        }
+
+        match mode {
+            ViewMode::Week | ViewMode::Month => 3,
+            ViewMode::Year | ViewMode::Decade => 2,
+        }
+    }
+
+    /// Column at which the ISO week numbers of the `ViewMode::Month` view
+    /// mode are drawn.
+    fn iso_week_x(&self) -> i32 {
+        if self.rtl {
+            self.h_offset(ViewMode::Month) + 7 * self.grid_cell_width()
+        } else {
+            0
+        }
+    }
+
+    fn date_available(&self, date: &Date<T>) -> bool {
+        if self.week_day_disabled(date) || !self.business_day(date) || self.date_disabled(date) {
+            return false;
+        }
+
+        if let Some(ref earliest) = self.earliest_date {
+            if *date < *earliest {
+                return false;
+            }
+        }
+
+        if let Some(ref latest) = self.latest_date {
+            if *date > *latest {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Whether the given date is selected, i.e. part of the selected dates in
+    /// multi select mode or the selected date otherwise.
+    fn date_selected(&self, date: &Date<T>) -> bool {
+        if self.multi_select {
+            self.selected_dates.contains(date)
+        } else {
+            *date == self.date
+        }
+    }
+
+    fn week_day_disabled(&self, date: &Date<T>) -> bool {
+        let week_day = date.weekday() as i32;
+        self.disabled_week_days.iter().any(|day| {
+            let day: i32 = (*day).into();
+            day == week_day
+        })
+    }
+
+    fn business_day(&self, date: &Date<T>) -> bool {
+        !self.business_days_only || !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+    }
+
+    /// Moves the given date in the direction of `step` until it no longer
+    /// falls on a weekend or, if `skip_disabled` is set, a disabled week day.
+    ///
+    /// A `step` of `0` moves to the nearest such date in either direction.
+    fn skip_disabled_week_days(&self, date: Date<T>, step: i32, skip_disabled: bool) -> Date<T> {
+        for i in 0..7 {
+            let offset = if step == 0 {
+                // 0, 1, -1, 2, -2, ...
+                if i % 2 == 1 {
+                    (i + 1) / 2
+                } else {
+                    -(i / 2)
+                }
+            } else {
+                i * step
+            };
+
+            let next = match date_from_day_and_offsets(&date, None, offset, 0, 0) {
+                Some(next) => next,
+                None => break,
+            };
+
+            if !(skip_disabled && self.week_day_disabled(&next)) && self.business_day(&next) {
+                return next;
+            }
+        }
+        date
+    }
+
+    fn month_available(&self, month: u32, year: i32) -> bool {
+        if !self.year_available(year) {
+            return false;
+        }
+
+        if let Some(ref earliest) = self.earliest_date {
+            if year == earliest.year() && month < earliest.month0() {
+                return false;
+            }
+        }
+
+        if let Some(ref latest) = self.latest_date {
+            if year == latest.year() && month > latest.month0() {
+                return false;
+            }
+        }
+
+        !self.month_disabled(month, year)
+    }
+
+    /// Returns the offset from the currently viewed month to the nearest
+    /// month in the direction of `step` which contains at least one
+    /// selectable day.
+    ///
+    /// Returns `None` if no such month is found within the search window.
+    fn available_month_offset(&self, step: i32) -> Option<i32> {
+        let step = step.signum();
+        for i in 1..=SEARCH_WINDOW_MONTHS {
+            let date = date_from_day_and_offsets(&self.view_date, Some(0), 0, i * step, 0)?;
+            if self.month_available(date.month0(), date.year()) {
+                return Some(i * step);
+            }
+            // All further months lie beyond the earliest or latest date
+            if self.exceeded_boundary(&date).is_some() {
+                return None;
+            }
+        }
+        None
+    }
+
+    fn year_available(&self, year: i32) -> bool {
+        if let Some(ref earliest) = self.earliest_date {
+            if year < earliest.year() {
+                return false;
+            }
+        }
+
+        if let Some(ref latest) = self.latest_date {
+            if year > latest.year() {
+                return false;
+            }
+        }
+
+        !self.has_disabled_dates() || (0..12).any(|month| !self.month_disabled(month, year))
+    }
+
+    fn has_disabled_dates(&self) -> bool {
+        self.disabled_dates.is_some() || !self.blackout_dates.is_empty()
+    }
+
+    fn date_disabled(&self, date: &Date<T>) -> bool {
+        if self.blackout_dates.contains(date) {
+            return true;
+        }
+
+        match self.disabled_dates {
+            Some(ref f) => f(date),
+            None => false,
+        }
+    }
+
+    /// Returns `true` if every day of the given month is disabled by either
+    /// the disabled dates predicate or the blackout dates.
+    fn month_disabled(&self, month: u32, year: i32) -> bool {
+        if !self.has_disabled_dates() {
+            return false;
+        }
+
+        let number_of_days = Month::from(month).number_of_days(year) as u32;
+        (1..=number_of_days)
+            .filter_map(|day| {
+                self.view_date
+                    .timezone()
+                    .ymd_opt(year, month + 1, day)
+                    .single()
+            })
+            .all(|date| self.date_disabled(&date))
+    }
+
+    /// First year of the decade which is currently shown in the
+    /// `ViewMode::Decade` view mode.
+    fn decade_start(&self) -> i32 {
+        let view_year = self.view_date.year();
+        view_year - view_year.rem_euclid(self.decade_size as i32)
+    }
+
+    /// Color of the `ViewMode::Decade` header, greyed out if the shown
+    /// decade contains no selectable year.
+    fn decade_header_color(&self) -> ColorStyle {
+        if self.decade_available(self.decade_start()) {
+            self.style.header
+        } else {
+            self.style.unavailable
+        }
+    }
+
+    fn decade_available(&self, decade: i32) -> bool {
+        (decade..decade + self.decade_size as i32).any(|year| self.year_available(year))
+    }
+
+    fn submit(&mut self) -> EventResult
+    where
+        T: 'static,
+    {
+        if self.view_mode == self.lowest_view_mode {
+            if self.view_mode <= ViewMode::Month && !self.date_available(&self.view_date) {
+                return EventResult::Consumed(None);
+            }
+
+            let (month, year) = (self.view_date.month0(), self.view_date.year());
+            if (self.view_mode == ViewMode::Year && !self.month_available(month, year))
+                || (self.view_mode == ViewMode::Decade && !self.year_available(year))
+            {
+                return EventResult::Consumed(None);
+            }
+
+            if self.multi_select && self.view_mode == ViewMode::Month {
+                return self.toggle_selected_date();
+            }
+
+            if self.range_select && self.view_mode == ViewMode::Month {
+                return self.select_range_end();
+            }
+
+            self.date = if self.view_mode == ViewMode::Week {
+                match self.week_start_of(&self.view_date) {
+                    Some(start) => start,
+                    None => return EventResult::Consumed(None),
+                }
+            } else {
+                self.view_date.clone()
+            };
+            self.submitted = true;
+
+            if self.on_submit.is_some() {
+                let cb = self.on_submit.clone().unwrap();
+                let date = self.date.clone();
+                return EventResult::Consumed(Some(Callback::from_fn(move |s| cb(s, &date))));
+            }
+        } else {
+            self.view_mode = match self.view_mode {
+                ViewMode::Week | ViewMode::Month => ViewMode::Week,
+                ViewMode::Year => ViewMode::Month,
+                ViewMode::Decade if self.fast_drill => self.lowest_view_mode,
+                ViewMode::Decade => ViewMode::Year,
+            };
+
+            if self.view_mode == ViewMode::Month && self.business_days_only {
+                self.view_date = self.skip_disabled_week_days(self.view_date.clone(), 0, false);
+            }
+        }
+        EventResult::Consumed(None)
+    }
+
+    fn select_range_end(&mut self) -> EventResult
+    where
+        T: 'static,
+    {
+        if !self.date_available(&self.view_date) {
+            return EventResult::Consumed(None);
+        }
+
+        let anchor = match self.range_anchor.take() {
+            Some(anchor) => anchor,
+            None => {
+                self.range_anchor = Some(self.view_date.clone());
+                return EventResult::Consumed(None);
+            }
+        };
+
+        let (start, end) = if anchor <= self.view_date {
+            (anchor, self.view_date.clone())
+        } else {
+            (self.view_date.clone(), anchor)
+        };
+        self.selected_range = Some((start.clone(), end.clone()));
+
+        EventResult::Consumed(
+            self.on_range_submit
+                .clone()
+                .map(|cb| Callback::from_fn(move |s| cb(s, &start, &end))),
+        )
+    }
+
+    /// The selected range or, while a range is being selected, the range
+    /// between its anchor and the cursor.
+    fn highlighted_range(&self) -> Option<(Date<T>, Date<T>)> {
+        if !self.range_select {
+            return None;
+        }
+
+        match self.range_anchor {
+            Some(ref anchor) if *anchor <= self.view_date => {
+                Some((anchor.clone(), self.view_date.clone()))
+            }
+            Some(ref anchor) => Some((self.view_date.clone(), anchor.clone())),
+            None => self.selected_range.clone(),
+        }
+    }
+
+    fn toggle_selected_date(&mut self) -> EventResult
+    where
+        T: 'static,
+    {
+        if !self.date_available(&self.view_date) {
+            return EventResult::Consumed(None);
+        }
+
+        if !self.selected_dates.remove(&self.view_date) {
+            self.selected_dates.insert(self.view_date.clone());
+        }
+
+        let dates = self.selected_dates();
+        EventResult::Consumed(
+            self.on_multi_change
+                .clone()
+                .map(|cb| Callback::from_fn(move |s| cb(s, &dates))),
+        )
+    }
+
+    /// Submits the cursor position on `<Enter>`, detecting quick double
+    /// presses if enabled.
+    fn submit_enter(&mut self) -> EventResult
+    where
+        T: 'static,
+    {
+        let double_enter = match self.last_drill.take() {
+            Some((time, mode, date)) if time.elapsed() < DOUBLE_ENTER_TIMEOUT => {
+                Some(mode).filter(|_| date == self.view_date)
+            }
+            _ => None,
+        };
+
+        if let Some(mode) = double_enter {
+            let first_day = match mode {
+                ViewMode::Decade => self.view_date.with_day0(0).and_then(|d| d.with_month0(0)),
+                _ => self.view_date.with_day0(0),
+            };
+            if let Some(first_day) = first_day {
+                self.set_view_date(first_day);
+            }
+            self.view_mode = self.lowest_view_mode;
+            return self.submit();
+        }
+
+        let mode = self.view_mode;
+        let result = self.submit();
+        if self.enter_commits_immediately && self.view_mode != mode {
+            self.last_drill = Some((Instant::now(), mode, self.view_date.clone()));
+        }
+        result
+    }
+
+    fn handle_event(&mut self, event: Event) -> EventResult
+    where
+        T: 'static,
+    {
+        let last_view_date = self.view_date.clone();
+        let action = self.key_bindings.action(&event);
+
+        if event == Event::FocusLost {
+            return match self.on_blur.clone() {
+                Some(cb) => EventResult::Consumed(Some(cb)),
+                None => EventResult::Ignored,
+            };
+        }
+
+        match event {
+            Event::Char(_) | Event::Refresh | Event::WindowResize => {}
+            _ => self.type_ahead = None,
+        }
+
+        // Month names take precedence over a character bound as today key
+        if let Event::Char(c) = event {
+            if action.is_none() && self.view_mode == ViewMode::Year {
+                let result = self.type_ahead(c, &last_view_date);
+                if let EventResult::Consumed(_) = result {
+                    return result;
+                }
+            }
+        }
+
+        if action.is_none() && self.today_key.as_ref() == Some(&event) {
+            let mut today = self.today.clone();
+
+            // Jump to today's month within a locked year instead
+            if self.year_locked && self.view_mode == ViewMode::Year {
+                let year_offset = last_view_date.year() - today.year();
+                today = match date_with_clamped_day(&today, today.day0(), 0, year_offset) {
+                    Some(date) => date,
+                    None => return EventResult::Consumed(None),
+                };
+            }
+
+            let skip_disabled = self.skip_disabled_on_nav && self.view_mode <= ViewMode::Month;
+            if skip_disabled || (self.business_days_only && self.view_mode <= ViewMode::Month) {
+                today = self.skip_disabled_week_days(today, 0, skip_disabled);
+            }
+
+            self.set_view_date(today);
+            return self.select_result(&last_view_date);
+        }
+
+        let offsets = match event {
+            _ if action == Some(KeyAction::Ascend) => {
+                if !self.ascend() {
+                    return EventResult::Consumed(self.on_mode_locked.clone());
+                }
+                None
+            }
+            _ if action == Some(KeyAction::Submit) => {
+                return self.submit_enter();
+            }
+            Event::Mouse {
+                event: MouseEvent::Press(MouseButton::Right),
+                ..
+            } => {
+                if !self.ascend() {
+                    return EventResult::Ignored;
+                }
+                None
+            }
+            // Scrolling pages regardless of the cell below the mouse
+            Event::Mouse {
+                event: MouseEvent::WheelUp,
+                ..
+            } => return self.navigate(KeyAction::PageBack),
+            Event::Mouse {
+                event: MouseEvent::WheelDown,
+                ..
+            } => return self.navigate(KeyAction::PageForward),
+            Event::Mouse {
+                position,
+                offset,
+                event: MouseEvent::Press(btn),
+            } => {
+                let offset = offset + self.content_offset(self.layout_size);
+                let position = match position.checked_sub(offset) {
+                    Some(position) => position,
+                    None => return EventResult::Ignored,
+                };
+                match self.view_mode {
+                    ViewMode::Decade => {
+                        let h_offset = self.h_offset(ViewMode::Decade) as usize;
+                        if position.y < 2
+                            || position.y % 2 != 0
+                            || position.x < h_offset
+                            || position.x >= h_offset + 4 * 5
+                            || (position.x - h_offset) % 5 == 4
+                        {
+                            return EventResult::Ignored;
+                        }
+                        let cell_index =
+                            self.grid_column((position.x - h_offset) / 5, 4) + (position.y - 2) * 2;
+                        if cell_index >= self.decade_size as usize + 3 {
+                            return EventResult::Ignored;
+                        }
+                        let current_index = 1 + last_view_date.year() - self.decade_start();
+
+                        let offset = cell_index as i32 - current_index;
+                        if offset == 0 && btn == MouseButton::Left {
+                            return self.submit();
+                        }
+                        Some((0, 0, offset))
+                    }
+                    ViewMode::Year => {
+                        let h_offset = self.h_offset(ViewMode::Year) as usize;
+                        let cell_width = self.year_cell_width() as usize;
+                        if position.y < 2
+                            || position.y > 6
+                            || position.y % 2 != 0
+                            || position.x < h_offset
+                            || position.x >= h_offset + 4 * cell_width
+                            || (position.x - h_offset) % cell_width == cell_width - 1
+                        {
+                            return EventResult::Ignored;
+                        }
+                        let month = 4 * (position.y.saturating_sub(2) / 2)
+                            + self.grid_column((position.x - h_offset) / cell_width, 4);
+                        let offset = month as i32 - last_view_date.month0() as i32;
+                        if offset == 0 && btn == MouseButton::Left {
+                            return self.submit();
+                        }
+                        Some((0, offset, 0))
+                    }
+                    ViewMode::Week | ViewMode::Month if position.y == 0 => {
+                        match self.header_month_offset(position.x) {
+                            Some(month) => Some((0, month, 0)),
+                            None => return EventResult::Ignored,
+                        }
+                    }
+                    ViewMode::Week | ViewMode::Month => {
+                        let h_offset = self.h_offset(ViewMode::Month) as usize;
+                        let cell_width = self.grid_cell_width() as usize;
+
+                        if position.y < 2
+                            || position.y >= 2 + self.grid_rows()
+                            || position.x < h_offset
+                            || position.x >= h_offset + 7 * cell_width
+                            || (position.x - h_offset) % cell_width == cell_width - 1
+                        {
+                            return EventResult::Ignored;
+                        }
+
+                        let cell_index = self.grid_column((position.x - h_offset) / cell_width, 7)
+                            + 7 * (position.y - 2);
+
+                        let current_index = last_view_date.day0() as i32 + self.month_day_offset();
+
+                        let offset = cell_index as i32 - current_index;
+                        if offset == 0 && btn == MouseButton::Left {
+                            return self.submit();
+                        }
+
+                        if let Some(date) =
+                            date_from_day_and_offsets(&last_view_date, None, offset, 0, 0)
+                        {
+                            let current_month = self.is_current_month(&date);
+                            if !self.show_adjacent_days && !current_month {
+                                return EventResult::Ignored;
+                            }
+                            if self.click_adjacent_centers && !current_month {
+                                // Never clamp the cursor onto a different day
+                                if !self.date_available(&date) {
+                                    return EventResult::Ignored;
+                                }
+                                self.set_view_date(date);
+                                return self.select_result(&last_view_date);
+                            }
+
+                            if self.click_selects_any
+                                && btn == MouseButton::Left
+                                && current_month
+                                && self.date_available(&date)
+                            {
+                                self.set_view_date(date);
+                                return self.select_result(&last_view_date).and(self.submit());
+                            }
+                        }
+                        Some((offset, 0, 0))
+                    }
+                }
+            }
+            _ => match action {
+                Some(action) => return self.navigate(action),
+                None => return EventResult::Ignored,
+            },
+        };
+
+        self.apply_offsets(&last_view_date, offsets, false, false, None)
+    }
+
+    /// Moves the cursor to the next month of the viewed year whose localized
+    /// name starts with the characters typed so far.
+    fn type_ahead(&mut self, c: char, last_view_date: &Date<T>) -> EventResult
+    where
+        T: 'static,
+    {
+        let mut buffer = match self.type_ahead.take() {
+            Some((time, buffer)) if time.elapsed() < TYPE_AHEAD_TIMEOUT => buffer,
+            _ => String::new(),
+        };
+        buffer.extend(c.to_lowercase());
+
+        // Repeating a single character cycles through all months starting
+        // with it, starting after the current one
+        let first = buffer.chars().next();
+        let cycle = buffer.chars().all(|c| Some(c) == first);
+        let prefix: String = if cycle {
+            buffer.chars().take(1).collect()
+        } else {
+            buffer.clone()
+        };
+        let start = if cycle { 1 } else { 0 };
+
+        let year = last_view_date.year();
+        let view_month = last_view_date.month0() as i32;
+        let month = (start..start + 12)
+            .map(|offset| (view_month + offset).rem_euclid(12) as u32)
+            .find(|month| {
+                self.month_available(*month, year)
+                    && self
+                        .runtime_locale()
+                        .month(Month::from(*month), true)
+                        .to_lowercase()
+                        .starts_with(&prefix)
+            });
+
+        match month {
+            Some(month) => {
+                self.type_ahead = Some((Instant::now(), buffer));
+                let offset = month as i32 - view_month;
+                if let Some(date) =
+                    date_with_clamped_day(last_view_date, last_view_date.day0(), offset, 0)
+                {
+                    self.set_view_date(date);
+                }
+                self.select_result(last_view_date)
+            }
+            None => EventResult::Ignored,
+        }
+    }
+
+    /// Switches to the next higher view mode, unless the highest view mode
+    /// has already been reached.
+    fn ascend(&mut self) -> bool {
+        if self.view_mode >= self.highest_view_mode {
+            return false;
+        }
+
+        self.view_mode = match self.view_mode {
+            ViewMode::Week => ViewMode::Month,
+            ViewMode::Month => ViewMode::Year,
+            ViewMode::Year | ViewMode::Decade => ViewMode::Decade,
+        };
+        true
+    }
+
+    /// Moves the cursor according to the given navigation action.
+    fn navigate(&mut self, action: KeyAction) -> EventResult
+    where
+        T: 'static,
+    {
+        let last_view_date = self.view_date.clone();
+        let arrow = matches!(
+            action,
+            KeyAction::MovePrev | KeyAction::MoveNext | KeyAction::MoveUp | KeyAction::MoveDown
+        );
+        let skip_disabled = arrow && self.skip_disabled_on_nav && self.view_mode <= ViewMode::Month;
+        let wrap = arrow && self.navigation_mode == NavMode::Wrap;
+
+        let mut boundary = None;
+        let offsets = match self.action_offset(action) {
+            // Skip months without any selectable days when paging
+            Some((0, month, 0))
+                if self.view_mode <= ViewMode::Month
+                    && matches!(action, KeyAction::PageBack | KeyAction::PageForward) =>
+            {
+                match self.available_month_offset(month) {
+                    Some(month) => Some((0, month, 0)),
+                    None => {
+                        boundary = Some(if month < 0 {
+                            Boundary::Earliest
+                        } else {
+                            Boundary::Latest
+                        });
+                        None
+                    }
+                }
+            }
+            offsets => offsets,
+        };
+
+        self.apply_offsets(&last_view_date, offsets, skip_disabled, wrap, boundary)
+    }
+
+    /// Moves the cursor by the given `(day, month, year)` offsets, if any.
+    fn apply_offsets(
+        &mut self,
+        last_view_date: &Date<T>,
+        offsets: Option<(i32, i32, i32)>,
+        skip_disabled: bool,
+        wrap: bool,
+        mut boundary: Option<Boundary>,
+    ) -> EventResult
+    where
+        T: 'static,
+    {
+        if let Some((day, month, year)) = offsets {
+            // Keep the originally intended day across months which are too
+            // short to contain it
+            let sticky_day = if self.sticky_day_of_month && day == 0 {
+                Some(self.sticky_day.unwrap_or_else(|| last_view_date.day0()))
+            } else {
+                None
+            };
+
+            let date = match sticky_day {
+                Some(day0) => date_with_clamped_day(last_view_date, day0, month, year),
+                None => date_from_day_and_offsets(last_view_date, None, day, month, year),
+            };
+
+            // Clamp to the range of dates chrono can represent instead of
+            // ignoring the navigation
+            let date = date.or_else(|| Self::navigation_limit(last_view_date, (day, month, year)));
+
+            let date = date.filter(|date| {
+                !(self.year_locked
+                    && self.view_mode == ViewMode::Year
+                    && date.year() != last_view_date.year())
+            });
+
+            if let Some(mut date) = date {
+                if skip_disabled || (self.business_days_only && self.view_mode <= ViewMode::Month) {
+                    date = self.skip_disabled_week_days(date, day.signum(), skip_disabled);
+                }
+                boundary = self.exceeded_boundary(&date);
+                if wrap {
+                    if let Some(wrapped) = boundary.and_then(|b| self.wrapped_date(b)) {
+                        date = wrapped;
+                    }
+                }
+                self.set_view_date(date);
+                self.sticky_day = sticky_day;
+            }
+        }
+
+        let result = self.select_result(last_view_date);
+        match (boundary, self.on_boundary.clone()) {
+            (Some(boundary), Some(cb)) => {
+                result.and(EventResult::Consumed(Some(Callback::from_fn(move |s| {
+                    cb(s, boundary)
+                }))))
+            }
+            _ => result,
+        }
+    }
+
+    /// The first or last date chrono can represent, if navigating from the
+    /// given date by the given offsets leaves that range.
+    ///
+    /// Returns `None` for targets within the range, whose navigation failed
+    /// for other reasons, e.g. a local date which does not exist.
+    fn navigation_limit(date: &Date<T>, (day, month, year): (i32, i32, i32)) -> Option<Date<T>> {
+        let naive = date.naive_local();
+        let target_year = i64::from(naive.year())
+            + i64::from(year)
+            + (i64::from(naive.month0()) + i64::from(month)).div_euclid(12);
+        let limit = if target_year < i64::from(NaiveDate::MIN.year()) {
+            NaiveDate::MIN
+        } else if target_year > i64::from(NaiveDate::MAX.year()) {
+            NaiveDate::MAX
+        } else if naive
+            .checked_add_signed(chrono::Duration::days(i64::from(day)))
+            .is_none()
+        {
+            if day < 0 {
+                NaiveDate::MIN
+            } else {
+                NaiveDate::MAX
+            }
+        } else {
+            return None;
+        };
+        date.timezone().from_local_date(&limit).single()
+    }
+
+    /// The date `SEARCH_WINDOW_DAYS` days before or after the view date,
+    /// stopping early at the limits of chrono's supported range.
+    fn search_window_edge(&self, forward: bool) -> Date<T> {
+        let mut date = self.view_date.clone();
+        for _ in 0..SEARCH_WINDOW_DAYS {
+            let next = if forward {
+                date.succ_opt()
+            } else {
+                date.pred_opt()
+            };
+            match next {
+                Some(next) => date = next,
+                None => break,
+            }
+        }
+        date
+    }
+
+    /// The selectable date at the opposite end of the allowed range, if
+    /// both the earliest and latest date are set.
+    fn wrapped_date(&self, boundary: Boundary) -> Option<Date<T>> {
+        self.earliest_date.as_ref()?;
+        self.latest_date.as_ref()?;
+        match boundary {
+            Boundary::Earliest => self.last_selectable().or_else(|| self.latest_date.clone()),
+            Boundary::Latest => self
+                .first_selectable()
+                .or_else(|| self.earliest_date.clone()),
+        }
+    }
+
+    /// The limit the given date lies beyond, if any.
+    fn exceeded_boundary(&self, date: &Date<T>) -> Option<Boundary> {
+        match (&self.earliest_date, &self.latest_date) {
+            (Some(earliest), _) if date < earliest => Some(Boundary::Earliest),
+            (_, Some(latest)) if date > latest => Some(Boundary::Latest),
+            _ => None,
+        }
+    }
+
+    fn select_result(&self, last_view_date: &Date<T>) -> EventResult
+    where
+        T: 'static,
+    {
+        if self.view_date != *last_view_date {
+            let date = self.view_date.clone();
+            EventResult::Consumed(
+                self.on_select
+                    .clone()
+                    .map(|cb| Callback::from_fn(move |s| cb(s, &date))),
+            )
+        } else {
+            EventResult::Consumed(None)
+        }
+    }
+}
+
+impl<T: TimeZone + Send + Sync + 'static, L: Locale + Send + Sync + 'static> View
+    for CalendarView<T, L>
+where
+    T::Offset: Send + Sync,
+{
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        // Align against the current printer size, which reflects resizes
+        // immediately
+        let printer = &printer.offset(self.content_offset(printer.size));
+
+        if self.enabled {
+            self.draw_view_mode(printer);
+        } else {
+            printer.with_color(self.style.disabled, |printer| {
+                self.draw_view_mode(printer);
+            });
+        }
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        // The ISO week gutter is part of the grid on either side
+        let grid_width = |mode: ViewMode, columns: i32, cell_width: i32| {
+            let gutter = if self.rtl {
+                self.iso_gutter_width(mode)
+            } else {
+                0
+            };
+            self.h_offset(mode) + gutter + columns * cell_width - 1
+        };
+        let grid_width = cmp::max(
+            grid_width(ViewMode::Month, 7, self.grid_cell_width()),
+            grid_width(ViewMode::Year, 4, self.year_cell_width()),
+        );
+
+        // Leave room for the right-hand monochrome marker
+        let marker_width = if self.monochrome_markers { 1 } else { 0 };
+
+        let height = self.content_height();
+
+        // Widen the view for locales with long month names, so the header
+        // of the `ViewMode::Month` view mode never clips
+        let header_width = (0..12)
+            .map(|month| {
+                format!(
+                    "{} {}",
+                    self.runtime_locale().month(Month::from(month), true),
+                    self.runtime_locale().era_label(self.view_date.year())
+                )
+                .chars()
+                .count()
+            })
+            .chain(Some(self.header_title().chars().count()))
+            .chain(
+                Some(self.day_of_year_label().chars().count())
+                    .filter(|_| self.show_day_of_year_footer && self.view_mode <= ViewMode::Month),
+            )
+            .max()
+            .unwrap_or(0);
+
+        let width = cmp::max((grid_width + marker_width) as usize, header_width);
+        self.size = (width, height).into();
+
+        if self.center_in_parent {
+            Vec2::new(
+                cmp::max(self.size.x, constraint.x),
+                cmp::max(self.size.y, constraint.y),
+            )
+        } else {
+            self.size
+        }
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.layout_size = size;
+        self.invalidated = false;
+    }
+
+    fn needs_relayout(&self) -> bool {
+        self.invalidated || self.size.y != self.content_height()
+    }
+
+    fn take_focus(&mut self, _: Direction) -> Result<EventResult, CannotFocus> {
+        if self.enabled {
+            Ok(EventResult::Consumed(self.on_focus.clone()))
+        } else {
+            Err(CannotFocus)
+        }
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if !self.enabled {
+            return EventResult::Ignored;
+        }
+
+        let last_state = self.state();
+        let mut result = self.handle_event(event);
+
+        if self.silent {
+            return match result {
+                EventResult::Consumed(_) => EventResult::Consumed(None),
+                EventResult::Ignored => EventResult::Ignored,
+            };
+        }
+
+        if let Some(cb) = self.on_view_mode_change.clone() {
+            let mode = self.view_mode;
+            if mode != last_state.view_mode {
+                result = result.and(EventResult::Consumed(Some(Callback::from_fn(move |s| {
+                    cb(s, mode)
+                }))));
+            }
+        }
+
+        match self.on_change.clone() {
+            Some(cb) if self.state() != last_state => {
+                let state = self.state();
+                result.and(EventResult::Consumed(Some(Callback::from_fn(move |s| {
+                    cb(s, &state)
+                }))))
+            }
+            _ => result,
+        }
+    }
+}
+
+// Helpers --------------------------------------------------------------------
+pub(crate) fn clone_bound<D: Clone>(bound: Bound<&D>) -> Bound<D> {
+    match bound {
+        Bound::Included(value) => Bound::Included(value.clone()),
+        Bound::Excluded(value) => Bound::Excluded(value.clone()),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+fn year_cell_label(locale: &dyn RuntimeLocale, year: i32) -> String {
+    // Fall back to the plain year number of the era if the localized
+    // label does not fit into a grid cell
+    let label = locale.era_label(year);
+    if label.chars().count() <= 4 {
+        label
+    } else if year < 1 {
+        (1 - year).to_string()
+    } else {
+        year.to_string()
+    }
+}
+
+fn date_from_day_and_offsets<T: TimeZone>(
+    date: &Date<T>,
+    set_day: Option<i32>,
+    day_offset: i32,
+    month_offset: i32,
+    year_offset: i32,
+) -> Option<Date<T>> {
+    let mut year = date.year() + year_offset;
+    let mut month = date.month0() as i32;
+
+    month += month_offset;
+
+    while month < 0 {
+        year -= 1;
+        month += 12;
+    }
+
+    while month >= 12 {
+        month -= 12;
+        year += 1;
+    }
+
+    let d = date
+        .with_day0(0)?
+        .with_year(year)?
+        .with_month0(month as u32)?;
+
+    let month: Month = d.month0().into();
+    let number_of_days = month.number_of_days(year);
+
+    let mut day = set_day.unwrap_or_else(|| cmp::min(number_of_days - 1, date.day0() as i32));
+
+    day += day_offset;
+    if day < 0 {
+        day += month.prev_number_of_days(year);
+        date_from_day_and_offsets(&d, Some(day), 0, -1, 0)
+    } else if day >= number_of_days {
+        day -= number_of_days;
+        date_from_day_and_offsets(&d, Some(day), 0, 1, 0)
+    } else {
+        d.with_day0(day as u32)
+    }
+}
+
+fn date_with_clamped_day<T: TimeZone>(
+    date: &Date<T>,
+    day0: u32,
+    month_offset: i32,
+    year_offset: i32,
+) -> Option<Date<T>> {
+    let d = date_from_day_and_offsets(date, Some(0), 0, month_offset, year_offset)?;
+    let month: Month = d.month0().into();
+    let number_of_days = month.number_of_days(d.year());
+    d.with_day0(cmp::min(number_of_days - 1, day0 as i32) as u32)
+}
+
+#[test]
+fn test_offsets() {
+    let date = Utc.ymd(1969, 7, 20);
+
+    // Moon landing
+    assert_eq!(
+        Some(Utc.ymd(1969, 7, 20)),
+        date_from_day_and_offsets(&date, None, 0, 0, 0)
+    );
+
+    // Mission start
+    assert_eq!(
+        Some(Utc.ymd(1969, 7, 16)),
+        date_from_day_and_offsets(&date, None, -4, 0, 0)
+    );
+
+    // Mission end
+    assert_eq!(
+        Some(Utc.ymd(1969, 7, 24)),
+        date_from_day_and_offsets(&date, None, 4, 0, 0)
+    );
+
+    // Quarantine lifted
+    assert_eq!(
+        Some(Utc.ymd(1969, 8, 10)),
+        date_from_day_and_offsets(&date, None, 21, 0, 0)
+    );
+    assert_eq!(
+        Some(Utc.ymd(1969, 8, 10)),
+        date_from_day_and_offsets(&date, None, -10, 1, 0)
+    );
+}
+
+#[test]
+fn test_iso_weeks_sunday_start() {
+    // January 2016 starts on a Friday, the first row begins in December 2015
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2016, 1, 14))
+        .week_start(WeekDay::Sunday)
+        .show_iso_weeks(true);
+
+    // Rows show the ISO week of their Thursday
+    assert_eq!(Some(Utc.ymd(2015, 12, 27)), calendar.month_grid_start());
+    assert_eq!(Some(53), calendar.row_iso_week(0));
+    assert_eq!(Some(1), calendar.row_iso_week(1));
+    assert_eq!(Some(2), calendar.row_iso_week(2));
+
+    // Monday start keeps the plain ISO week numbering
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2016, 1, 14));
+    assert_eq!(Some(Utc.ymd(2015, 12, 28)), calendar.month_grid_start());
+    assert_eq!(Some(53), calendar.row_iso_week(0));
+    assert_eq!(Some(1), calendar.row_iso_week(1));
+}
+
+#[test]
+fn test_week_row_count() {
+    // May 2017 starts on a Monday, no leading days from April
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 5, 10));
+    assert_eq!(0, calendar.month_day_offset());
+    assert_eq!(Some(Utc.ymd(2017, 5, 1)), calendar.month_grid_start());
+    assert_eq!(5, calendar.week_row_count());
+
+    // February 2010 starts on a Monday and fits into exactly four rows
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2010, 2, 10));
+    assert_eq!(0, calendar.month_day_offset());
+    assert_eq!(4, calendar.week_row_count());
+
+    // July 2017 starts on a Saturday and needs all six rows
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    assert_eq!(5, calendar.month_day_offset());
+    assert_eq!(6, calendar.week_row_count());
+
+    // With a Sunday week start, October 2017 has no leading days
+    let calendar =
+        CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 10, 1)).week_start(WeekDay::Sunday);
+    assert_eq!(0, calendar.month_day_offset());
+    assert_eq!(5, calendar.week_row_count());
+}
+
+#[test]
+fn test_decade_header_bounds() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .earliest_date(Some(Utc.ymd(2017, 1, 1)))
+        .latest_date(Some(Utc.ymd(2023, 12, 31)))
+        .disabled_dates(|date| date.year() >= 2020)
+        .view_mode(ViewMode::Decade);
+
+    assert!(calendar.decade_available(2010));
+    assert!(!calendar.decade_available(2000));
+    assert!(!calendar.decade_available(2020));
+    assert_eq!(ColorStyle::inherit_parent(), calendar.decade_header_color());
+
+    // Paging is clamped to the latest date, whose decade has no selectable
+    // year left
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(2023, 12, 31), calendar.view_date);
+    assert_eq!(ColorStyle::tertiary(), calendar.decade_header_color());
+
+    calendar.on_event(Event::Key(Key::PageUp));
+    calendar.on_event(Event::Key(Key::PageUp));
+    assert_eq!(Utc.ymd(2017, 1, 1), calendar.view_date);
+    assert_eq!(ColorStyle::inherit_parent(), calendar.decade_header_color());
+}
+
+#[test]
+fn test_submit_lowest_view_mode() {
+    // Year as the lowest mode commits the month under the cursor
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .lowest_view_mode(ViewMode::Year)
+        .view_mode(ViewMode::Decade);
+
+    calendar.set_view_date(Utc.ymd(2019, 7, 26));
+    calendar.commit();
+    assert!(calendar.view_mode == ViewMode::Year);
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.date());
+
+    calendar.set_view_date(Utc.ymd(2019, 3, 26));
+    calendar.commit();
+    assert!(calendar.view_mode == ViewMode::Year);
+    assert_eq!(Utc.ymd(2019, 3, 26), calendar.date());
+
+    // Decade as the lowest mode commits the year under the cursor
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    calendar.lowest_view_mode = ViewMode::Decade;
+    calendar.view_mode = ViewMode::Decade;
+
+    calendar.set_view_date(Utc.ymd(2014, 7, 26));
+    calendar.commit();
+    assert!(calendar.view_mode == ViewMode::Decade);
+    assert_eq!(Utc.ymd(2014, 7, 26), calendar.date());
+}
+
+#[test]
+fn test_relative_to_today() {
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    assert_eq!("Today", calendar.relative_to_today(&Utc.ymd(2017, 7, 26)));
+    assert_eq!(
+        "Tomorrow",
+        calendar.relative_to_today(&Utc.ymd(2017, 7, 27))
+    );
+    assert_eq!(
+        "Yesterday",
+        calendar.relative_to_today(&Utc.ymd(2017, 7, 25))
+    );
+    assert_eq!(
+        "In 7 days",
+        calendar.relative_to_today(&Utc.ymd(2017, 8, 2))
+    );
+    assert_eq!(
+        "26 days ago",
+        calendar.relative_to_today(&Utc.ymd(2017, 6, 30))
+    );
+}
+
+#[test]
+fn test_years_before_common_era() {
+    assert_eq!("1 BC", EnglishLocale::era_label(0));
+    assert_eq!("2 BC", EnglishLocale::era_label(-1));
+    assert_eq!("1", EnglishLocale::era_label(1));
+    assert_eq!("1 BC", year_cell_label(&DynLocale::English, 0));
+    assert_eq!("500", year_cell_label(&DynLocale::English, -499));
+
+    // Paging backwards across year 0
+    let mut calendar =
+        CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(1, 3, 1)).view_mode(ViewMode::Year);
+    calendar.on_event(Event::Key(Key::PageUp));
+    assert_eq!(Utc.ymd(0, 3, 1), calendar.view_date);
+    calendar.on_event(Event::Key(Key::PageUp));
+    assert_eq!(Utc.ymd(-1, 3, 1), calendar.view_date);
+
+    // Decades before year 0 are aligned like all others
+    calendar.set_view_mode(ViewMode::Decade);
+    assert_eq!(-10, calendar.decade_start());
+    assert_eq!((-11, true), calendar.visible_years()[0]);
+    assert_eq!((1, true), calendar.visible_years()[12]);
+
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(9, 3, 1), calendar.view_date);
+    assert_eq!(0, calendar.decade_start());
+}
+
+#[test]
+fn test_cell_color() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 12, 31))
+        .latest_date(Some(Utc.ymd(2018, 1, 5)));
+    calendar.set_view_date(Utc.ymd(2018, 1, 2));
+
+    assert_eq!(
+        ColorStyle::highlight(),
+        calendar.cell_color(&Utc.ymd(2018, 1, 2), true)
+    );
+    assert_eq!(
+        ColorStyle::highlight_inactive(),
+        calendar.cell_color(&Utc.ymd(2018, 1, 2), false)
+    );
+    assert_eq!(
+        ColorStyle::primary(),
+        calendar.cell_color(&Utc.ymd(2018, 1, 3), true)
+    );
+    assert_eq!(
+        ColorStyle::tertiary(),
+        calendar.cell_color(&Utc.ymd(2018, 1, 6), true)
+    );
+
+    // The selection in the previous year is highlighted among the adjacent days
+    assert_eq!(
+        ColorStyle::highlight_inactive(),
+        calendar.cell_color(&Utc.ymd(2017, 12, 31), true)
+    );
+    assert_eq!(
+        ColorStyle::secondary(),
+        calendar.cell_color(&Utc.ymd(2017, 12, 31), false)
+    );
+    assert_eq!(
+        ColorStyle::secondary(),
+        calendar.cell_color(&Utc.ymd(2017, 12, 30), true)
+    );
+}
+
+#[test]
+fn test_week_start_precedence() {
+    struct SundayLocale;
+
+    impl Locale for SundayLocale {
+        fn week_day(day: WeekDay, long_text: bool) -> &'static str {
+            EnglishLocale::week_day(day, long_text)
+        }
+
+        fn month(month: Month, long_text: bool) -> &'static str {
+            EnglishLocale::month(month, long_text)
+        }
+
+        fn week_start() -> WeekDay {
+            WeekDay::Sunday
+        }
+    }
+
+    let today = Utc.ymd(2017, 7, 26);
+
+    // Crate default
+    let calendar = CalendarView::<Utc, SundayLocale>::new(today);
+    assert_eq!(WeekDay::Monday as i32, calendar.week_start as i32);
+
+    // Locale default
+    let calendar = CalendarView::<Utc, SundayLocale>::with_locale_defaults(today);
+    assert_eq!(WeekDay::Sunday as i32, calendar.week_start as i32);
+
+    // Explicit setter
+    let mut calendar =
+        CalendarView::<Utc, SundayLocale>::with_locale_defaults(today).week_start(WeekDay::Tuesday);
+    assert_eq!(WeekDay::Tuesday as i32, calendar.week_start as i32);
+
+    calendar.apply_locale_defaults();
+    assert_eq!(WeekDay::Tuesday as i32, calendar.week_start as i32);
+}
+
+#[test]
+fn test_render_agenda() {
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    assert_eq!(
+        vec!["24 25 26 27 28 29 30", "31  1  2  3  4  5  6"],
+        calendar.render_agenda(2)
+    );
+
+    let calendar = calendar.week_start(WeekDay::Sunday);
+    assert_eq!(vec!["23 24 25 26 27 28 29"], calendar.render_agenda(1));
+}
+
+#[test]
+fn test_sticky_day_of_month() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 1, 31));
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(2017, 2, 28), calendar.view_date);
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(2017, 3, 28), calendar.view_date);
+
+    let mut calendar =
+        CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 1, 31)).sticky_day_of_month(true);
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(2017, 2, 28), calendar.view_date);
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(2017, 3, 31), calendar.view_date);
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(2017, 4, 30), calendar.view_date);
+
+    // Moving by days resets the intended day
+    calendar.on_event(Event::Key(Key::Left));
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(2017, 5, 29), calendar.view_date);
+}
+
+#[test]
+fn test_selected_timestamp() {
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(1969, 7, 20));
+    assert_eq!(Some(-14_256_000), calendar.selected_timestamp());
+    assert_eq!(Some(-14_256_000_000), calendar.selected_timestamp_millis());
+
+    let calendar = CalendarView::<FixedOffset, EnglishLocale>::new(
+        FixedOffset::east_opt(3600).unwrap().ymd(1970, 1, 2),
+    );
+    assert_eq!(Some(82_800), calendar.selected_timestamp());
+
+    // Midnight of 2017-03-26 does not exist, the day starts at 01:00 UTC+1
+    let calendar = CalendarView::<MidnightGap, EnglishLocale>::new(MidnightGap.ymd(2017, 3, 26));
+    assert_eq!(Some(1_490_486_400), calendar.selected_timestamp());
+    assert_eq!(
+        Some(1_490_486_400_000),
+        calendar.selected_timestamp_millis()
+    );
+}
+
+/// Time zone switching from UTC to UTC+1 at local midnight of 2017-03-26,
+/// skipping the local times from 00:00 to 01:00.
+#[cfg(test)]
+#[derive(Copy, Clone, Debug)]
+struct MidnightGap;
+
+#[cfg(test)]
+impl MidnightGap {
+    fn transition() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2017, 3, 26)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .unwrap()
+    }
+
+    fn offset(utc: &NaiveDateTime) -> FixedOffset {
+        let seconds = if *utc < Self::transition() { 0 } else { 3600 };
+        FixedOffset::east_opt(seconds).unwrap()
+    }
+}
+
+#[cfg(test)]
+impl TimeZone for MidnightGap {
+    type Offset = FixedOffset;
+
+    fn from_offset(_: &FixedOffset) -> Self {
+        MidnightGap
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> chrono::LocalResult<FixedOffset> {
+        self.offset_from_local_datetime(&local.and_hms_opt(12, 0, 0).unwrap())
+    }
+
+    fn offset_from_local_datetime(
+        &self,
+        local: &NaiveDateTime,
+    ) -> chrono::LocalResult<FixedOffset> {
+        let gap_end = Self::transition() + chrono::Duration::hours(1);
+        if *local < Self::transition() {
+            chrono::LocalResult::Single(Self::offset(local))
+        } else if *local < gap_end {
+            chrono::LocalResult::None
+        } else {
+            chrono::LocalResult::Single(Self::offset(&gap_end))
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+        Self::offset(&utc.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+        Self::offset(utc)
+    }
+}
+
+#[test]
+fn test_selected_julian_day() {
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2000, 1, 1));
+    assert_eq!(2_451_545, calendar.selected_julian_day());
+
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(1858, 11, 17));
+    assert_eq!(2_400_001, calendar.selected_julian_day());
+
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(-4713, 11, 24));
+    assert_eq!(0, calendar.selected_julian_day());
+}
+
+#[test]
+fn test_runtime_locale() {
+    struct ShoutingLocale;
+    impl RuntimeLocale for ShoutingLocale {
+        fn week_day(&self, day: WeekDay, long_text: bool) -> &'static str {
+            EnglishLocale::week_day(day, long_text)
+        }
+
+        fn month(&self, _: Month, _: bool) -> &'static str {
+            "MONTH"
+        }
+    }
+
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    assert_eq!("July", calendar.selected_month_name(true));
+
+    calendar.set_locale(DynLocale::Custom(Arc::new(ShoutingLocale)));
+    assert_eq!("MONTH", calendar.selected_month_name(true));
+
+    calendar.layout(Vec2::zero());
+    calendar.set_locale(DynLocale::English);
+    assert!(calendar.needs_relayout());
+
+    let locale: Box<dyn RuntimeLocale> = Box::new(ShoutingLocale);
+    calendar.set_locale(locale);
+    assert_eq!("MONTH", calendar.selected_month_name(true));
+
+    calendar.set_locale(DynLocale::English);
+    assert_eq!("July", calendar.selected_month_name(true));
+}
+
+#[test]
+fn test_week_number_base() {
+    // July 2017 grid starts on Monday, June 26th
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .show_iso_weeks(true)
+        .week_number_base(Utc.ymd(2017, 7, 5), 1);
+
+    assert_eq!(None, calendar.row_week_number(0));
+    assert_eq!(Some(1), calendar.row_week_number(1));
+    assert_eq!(Some(5), calendar.row_week_number(5));
+
+    calendar.set_week_number_base(Utc.ymd(2017, 7, 5), -1);
+    assert_eq!(None, calendar.row_week_number(1));
+    assert_eq!(Some(0), calendar.row_week_number(2));
+
+    calendar.clear_week_number_base();
+    assert_eq!(Some(26), calendar.row_week_number(0));
+}
+
+#[test]
+fn test_selected_row() {
+    // July 2017 starts on a Saturday
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 1));
+    assert_eq!(Some(0), calendar.selected_row());
+
+    calendar.set_selected_date(Utc.ymd(2017, 7, 3));
+    assert_eq!(Some(1), calendar.selected_row());
+
+    calendar.set_selected_date(Utc.ymd(2017, 7, 31));
+    assert_eq!(Some(5), calendar.selected_row());
+
+    calendar.set_view_date(Utc.ymd(2017, 8, 1));
+    assert_eq!(None, calendar.selected_row());
+}
+
+#[test]
+fn test_on_change() {
+    use std::sync::Mutex;
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let recorded = changes.clone();
+    let mut calendar =
+        CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26)).on_change(move |_, state| {
+            recorded
+                .lock()
+                .unwrap()
+                .push((state.date, state.view_date, state.view_mode));
+        });
+    let mut siv = Cursive::new();
+
+    // Changes of the view date and the date
+    calendar.on_event(Event::Key(Key::Right)).process(&mut siv);
+    calendar.on_event(Event::Key(Key::Enter)).process(&mut siv);
+
+    // No-op events
+    calendar.on_event(Event::Char('x')).process(&mut siv);
+    calendar.on_event(Event::Key(Key::Enter)).process(&mut siv);
+
+    // Change of the view mode
+    calendar
+        .on_event(Event::Key(Key::Backspace))
+        .process(&mut siv);
+
+    // Silent changes
+    calendar.set_silent(|calendar| {
+        calendar.on_event(Event::Key(Key::Right)).process(&mut siv);
+    });
+    assert_eq!(Utc.ymd(2017, 8, 27), calendar.view_date);
+
+    let changes = changes.lock().unwrap();
+    assert_eq!(3, changes.len());
+    assert_eq!(
+        (Utc.ymd(2017, 7, 26), Utc.ymd(2017, 7, 27)),
+        (changes[0].0, changes[0].1)
+    );
+    assert!(changes[0].2 == ViewMode::Month);
+    assert_eq!(
+        (Utc.ymd(2017, 7, 27), Utc.ymd(2017, 7, 27)),
+        (changes[1].0, changes[1].1)
+    );
+    assert!(changes[1].2 == ViewMode::Month);
+    assert_eq!(
+        (Utc.ymd(2017, 7, 27), Utc.ymd(2017, 7, 27)),
+        (changes[2].0, changes[2].1)
+    );
+    assert!(changes[2].2 == ViewMode::Year);
+}
+
+#[test]
+fn test_silent_callbacks() {
+    let mut calendar =
+        CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26)).on_submit(|_, _| {});
+
+    calendar.set_silent(|calendar| {
+        assert!(calendar.commit().is_none());
+        match calendar.on_event(Event::Key(Key::Enter)) {
+            EventResult::Consumed(None) => {}
+            _ => panic!("Expected the event to be consumed without a callback"),
+        }
+    });
+
+    assert!(calendar.commit().is_some());
+}
+
+#[test]
+fn test_disabled_reason() {
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .earliest_date(Some(Utc.ymd(2017, 7, 10)))
+        .disabled_reason(|date| {
+            assert!(*date < Utc.ymd(2017, 7, 10));
+            Some("Past date".to_string())
+        });
+
+    assert_eq!(
+        Some("Past date".to_string()),
+        calendar.get_disabled_reason(&Utc.ymd(2017, 7, 9))
+    );
+    assert_eq!(None, calendar.get_disabled_reason(&Utc.ymd(2017, 7, 10)));
+}
+
+#[test]
+fn test_business_days_only() {
+    // July 29th 2017 is a Saturday
+    let mut calendar =
+        CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 28)).business_days_only(true);
+
+    assert!(!calendar.is_date_available(&Utc.ymd(2017, 7, 29)));
+    assert!(calendar.is_date_available(&Utc.ymd(2017, 7, 31)));
+
+    calendar.on_event(Event::Key(Key::Right));
+    assert_eq!(Utc.ymd(2017, 7, 31), calendar.view_date);
+
+    calendar.on_event(Event::Key(Key::Left));
+    assert_eq!(Utc.ymd(2017, 7, 28), calendar.view_date);
+
+    calendar.set_view_date(Utc.ymd(2017, 7, 30));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(Utc.ymd(2017, 7, 28), calendar.date);
+}
+
+#[test]
+fn test_available_day_count() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .earliest_date(Some(Utc.ymd(2017, 7, 14)));
+    assert_eq!(31, calendar.total_day_count());
+    assert_eq!(18, calendar.available_day_count());
+
+    calendar.set_earliest_date(None);
+    calendar.set_view_date(Utc.ymd(2017, 2, 1));
+    assert_eq!(28, calendar.total_day_count());
+    assert_eq!(28, calendar.available_day_count());
+}
+
+#[test]
+fn test_cell_width() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    assert_eq!(3, calendar.grid_cell_width());
+
+    calendar.set_cell_width(1);
+    assert_eq!(3, calendar.grid_cell_width());
+
+    calendar.set_cell_width(5);
+    assert_eq!(6, calendar.grid_cell_width());
+    assert_eq!(Vec2::new(41, 8), calendar.required_size(Vec2::zero()));
+}
+
+#[test]
+fn test_reserve_iso_column() {
+    let mut calendar =
+        CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26)).reserve_iso_column(true);
+    assert_eq!(3, calendar.h_offset(ViewMode::Month));
+    assert_eq!(Vec2::new(23, 8), calendar.required_size(Vec2::zero()));
+
+    calendar.set_show_iso_weeks(true);
+    assert_eq!(3, calendar.h_offset(ViewMode::Month));
+    assert_eq!(Vec2::new(23, 8), calendar.required_size(Vec2::zero()));
+
+    calendar.set_reserve_iso_column(false);
+    calendar.set_show_iso_weeks(false);
+    assert_eq!(0, calendar.h_offset(ViewMode::Month));
+}
+
+#[test]
+fn test_vertical_align() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .vertical_align(VAlign::Center);
+    calendar.required_size(Vec2::zero());
+    calendar.layout(Vec2::new(20, 12));
+    assert_eq!(
+        Vec2::new(0, 2),
+        calendar.content_offset(calendar.layout_size)
+    );
+
+    // Clicking July 3rd, two rows below the top of the centered grid
+    calendar.on_event(Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(0, 5),
+        event: MouseEvent::Press(MouseButton::Left),
+    });
+    assert_eq!(Utc.ymd(2017, 7, 3), calendar.view_date);
+}
+
+#[test]
+fn test_date_range() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .date_range(Utc.ymd(2017, 7, 1)..Utc.ymd(2017, 8, 1));
+    assert_eq!(Some(Utc.ymd(2017, 7, 1)), calendar.earliest_date);
+    assert_eq!(Some(Utc.ymd(2017, 7, 31)), calendar.latest_date);
+
+    calendar.set_date_range(..=Utc.ymd(2017, 12, 31));
+    assert_eq!(None, calendar.earliest_date);
+    assert_eq!(Some(Utc.ymd(2017, 12, 31)), calendar.latest_date);
+
+    calendar.set_date_range(Utc.ymd(2017, 1, 1)..);
+    assert_eq!(Some(Utc.ymd(2017, 1, 1)), calendar.earliest_date);
+    assert_eq!(None, calendar.latest_date);
+
+    calendar.set_date_range(Utc.ymd(2017, 7, 26)..Utc.ymd(2017, 7, 27));
+    assert_eq!(Some(Utc.ymd(2017, 7, 26)), calendar.earliest_date);
+    assert_eq!(Some(Utc.ymd(2017, 7, 26)), calendar.latest_date);
+}
+
+#[test]
+fn test_date_range_normalized() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+
+    // Empty
+    calendar.set_date_range(Utc.ymd(2017, 7, 26)..Utc.ymd(2017, 7, 26));
+    assert_eq!(Some(Utc.ymd(2017, 7, 26)), calendar.earliest_date);
+    assert_eq!(Some(Utc.ymd(2017, 7, 26)), calendar.latest_date);
+
+    // Inverted
+    calendar.set_date_range(Utc.ymd(2017, 8, 1)..=Utc.ymd(2017, 7, 1));
+    assert_eq!(Some(Utc.ymd(2017, 7, 1)), calendar.earliest_date);
+    assert_eq!(Some(Utc.ymd(2017, 8, 1)), calendar.latest_date);
+
+    calendar.set_date_range(Utc.ymd(2017, 8, 1)..Utc.ymd(2017, 7, 1));
+    assert_eq!(Some(Utc.ymd(2017, 7, 1)), calendar.earliest_date);
+    assert_eq!(Some(Utc.ymd(2017, 7, 31)), calendar.latest_date);
+
+    // Exclusive bounds at the limits of the supported date range
+    let first = Utc.from_utc_date(&NaiveDate::MIN);
+    calendar.set_date_range(..first);
+    assert_eq!(None, calendar.earliest_date);
+    assert_eq!(Some(first), calendar.latest_date);
+}
+
+#[test]
+fn test_heatmap_color() {
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .heatmap(|date| (date.day0() * 8) as u8)
+        .heatmap_palette(vec![Color::TerminalDefault, Color::Rgb(255, 0, 0)]);
+
+    assert_eq!(None, calendar.heatmap_color(&Utc.ymd(2017, 7, 1)));
+    assert_eq!(
+        Some(Color::TerminalDefault),
+        calendar.heatmap_color(&Utc.ymd(2017, 7, 2))
+    );
+    assert_eq!(
+        Some(Color::Rgb(255, 0, 0)),
+        calendar.heatmap_color(&Utc.ymd(2017, 7, 31))
+    );
+}
+
+#[test]
+fn test_show_iso_week() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    calendar.set_view_mode(ViewMode::Decade);
+
+    calendar.show_iso_week(2020, 1);
+    assert_eq!(Utc.ymd(2019, 12, 30), calendar.view_date);
+    assert!(calendar.view_mode == ViewMode::Month);
+
+    calendar.show_iso_week(2020, 53);
+    assert_eq!(Utc.ymd(2020, 12, 28), calendar.view_date);
+
+    // 2021 has no 53rd week
+    calendar.show_iso_week(2021, 53);
+    assert_eq!(Utc.ymd(2020, 12, 28), calendar.view_date);
+
+    calendar.show_iso_week(2021, 1);
+    assert_eq!(Utc.ymd(2021, 1, 4), calendar.view_date);
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.date);
+
+    // Weeks are selected in the week view mode
+    calendar.set_lowest_view_mode(ViewMode::Week);
+    calendar.set_view_mode(ViewMode::Year);
+    calendar.show_iso_week(2020, 1);
+    assert_eq!(Utc.ymd(2019, 12, 30), calendar.view_date);
+    assert_eq!(Utc.ymd(2019, 12, 30), calendar.date);
+    assert!(calendar.view_mode == ViewMode::Week);
+}
+
+#[test]
+fn test_getters() {
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .view_date(Utc.ymd(2017, 8, 3))
+        .view_mode(ViewMode::Year)
+        .week_start(WeekDay::Sunday)
+        .show_iso_weeks(true)
+        .date_range(Utc.ymd(2017, 1, 1)..=Utc.ymd(2017, 12, 31));
+
+    assert_eq!(Utc.ymd(2017, 8, 3), calendar.get_view_date());
+    assert!(calendar.get_view_mode() == ViewMode::Year);
+    assert_eq!(WeekDay::Sunday as i32, calendar.get_week_start() as i32);
+    assert!(calendar.shows_iso_weeks());
+    assert_eq!(Some(Utc.ymd(2017, 1, 1)), calendar.get_earliest_date());
+    assert_eq!(Some(Utc.ymd(2017, 12, 31)), calendar.get_latest_date());
+}
+
+#[test]
+fn test_disabled_dates() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .disabled_dates(|date| date.month() == 2 || *date == Utc.ymd(2017, 12, 25));
+
+    assert!(!calendar.is_date_available(&Utc.ymd(2017, 12, 25)));
+    assert!(calendar.is_date_available(&Utc.ymd(2017, 12, 24)));
+    assert!(!calendar.month_available(1, 2017));
+    assert!(calendar.month_available(2, 2017));
+    assert!(calendar.year_available(2017));
+
+    calendar.set_view_date(Utc.ymd(2017, 12, 25));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.date);
+
+    calendar.set_disabled_dates(|date| date.year() == 2018);
+    assert!(!calendar.year_available(2018));
+}
+
+#[test]
+fn test_disabled_months_follow_changes() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .disabled_dates(|date| date.month() == 2);
+    assert!(!calendar.month_available(1, 2017));
+    assert!(calendar.month_available(2, 2017));
+
+    calendar.set_disabled_dates(|date| date.month() == 3);
+    assert!(calendar.month_available(1, 2017));
+    assert!(!calendar.month_available(2, 2017));
+
+    calendar.set_blackout_dates((1..=31).map(|day| Utc.ymd(2017, 1, day)).collect());
+    assert!(!calendar.month_available(0, 2017));
+
+    calendar.remove_blackout_date(&Utc.ymd(2017, 1, 31));
+    assert!(calendar.month_available(0, 2017));
+}
+
+#[test]
+fn test_submit_disabled_month_and_year() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .view_mode(ViewMode::Year)
+        .lowest_view_mode(ViewMode::Year)
+        .disabled_dates(|date| date.month() == 8 || date.year() == 2018);
+
+    calendar.set_view_date(Utc.ymd(2017, 8, 1));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.date);
+
+    calendar.set_view_date(Utc.ymd(2017, 9, 1));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(Utc.ymd(2017, 9, 1), calendar.date);
+
+    calendar.set_lowest_view_mode(ViewMode::Decade);
+    calendar.set_view_mode(ViewMode::Decade);
+    calendar.set_view_date(Utc.ymd(2018, 9, 1));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(Utc.ymd(2017, 9, 1), calendar.date);
+
+    calendar.set_view_date(Utc.ymd(2019, 9, 1));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(Utc.ymd(2019, 9, 1), calendar.date);
+}
+
+#[test]
+fn test_enter_commits_immediately() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .view_mode(ViewMode::Decade)
+        .enter_commits_immediately(true);
+
+    calendar.on_event(Event::Key(Key::Enter));
+    assert!(calendar.view_mode == ViewMode::Year);
+    calendar.on_event(Event::Key(Key::Enter));
+    assert!(calendar.view_mode == ViewMode::Month);
+    assert_eq!(Utc.ymd(2017, 1, 1), calendar.date);
+
+    calendar.set_view_mode(ViewMode::Year);
+    calendar.on_event(Event::Key(Key::Right));
+    calendar.on_event(Event::Key(Key::Enter));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(Utc.ymd(2017, 2, 1), calendar.date);
+
+    // Moving the cursor in between drills down as usual
+    calendar.set_view_mode(ViewMode::Year);
+    calendar.on_event(Event::Key(Key::Enter));
+    calendar.on_event(Event::Key(Key::Right));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(Utc.ymd(2017, 2, 2), calendar.date);
+}
+
+#[test]
+fn test_blackout_dates() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .blackout_dates(vec![Utc.ymd(2017, 12, 25)].into_iter().collect());
+
+    assert!(!calendar.is_date_available(&Utc.ymd(2017, 12, 25)));
+
+    calendar.add_blackout_date(Utc.ymd(2017, 12, 26));
+    assert!(!calendar.is_date_available(&Utc.ymd(2017, 12, 26)));
+
+    calendar.set_view_date(Utc.ymd(2017, 12, 26));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.date);
+
+    calendar.remove_blackout_date(&Utc.ymd(2017, 12, 26));
+    assert!(calendar.is_date_available(&Utc.ymd(2017, 12, 26)));
+}
+
+#[test]
+fn test_set_today() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    calendar.set_today(Utc.ymd(2017, 7, 27));
+
+    assert_eq!(Utc.ymd(2017, 7, 27), calendar.today());
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.date());
+    assert_eq!(
+        ColorStyle::title_primary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 27), false)
+    );
+    assert_eq!(
+        ColorStyle::primary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 25), false)
+    );
+}
+
+#[test]
+fn test_accessibility_label() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2024, 3, 14))
+        .view_date(Utc.ymd(2024, 3, 20));
+    assert_eq!(
+        "Month view, March 2024, 14th selected, cursor on 20th",
+        calendar.accessibility_label()
+    );
+
+    calendar.set_view_mode(ViewMode::Year);
+    calendar.set_view_date(Utc.ymd(2024, 11, 1));
+    assert_eq!(
+        "Year view, 2024, March 14th 2024 selected, cursor on November",
+        calendar.accessibility_label()
+    );
+
+    calendar.set_view_mode(ViewMode::Decade);
+    assert_eq!(
+        "Decade view, 2020 - 2029, March 14th 2024 selected, cursor on 2024",
+        calendar.accessibility_label()
+    );
+
+    assert_eq!("1st", EnglishLocale::ordinal_day(1));
+    assert_eq!("12th", EnglishLocale::ordinal_day(12));
+    assert_eq!("22nd", EnglishLocale::ordinal_day(22));
+    assert_eq!("23rd", EnglishLocale::ordinal_day(23));
+}
+
+#[test]
+#[cfg(feature = "locale-de")]
+fn test_accessibility_label_localized() {
+    let mut calendar = CalendarView::<Utc, GermanLocale>::new(Utc.ymd(2024, 3, 14))
+        .view_date(Utc.ymd(2024, 3, 20));
+    assert_eq!(
+        "Monatsansicht, März 2024, 14. ausgewählt, Cursor auf 20.",
+        calendar.accessibility_label()
+    );
+
+    calendar.set_view_mode(ViewMode::Year);
+    calendar.set_view_date(Utc.ymd(2024, 11, 1));
+    assert_eq!(
+        "Jahresansicht, 2024, 14. März 2024 ausgewählt, Cursor auf November",
+        calendar.accessibility_label()
+    );
+}
+
+#[test]
+fn test_week_view_mode() {
+    // July 26th 2017 is a Wednesday
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .lowest_view_mode(ViewMode::Week);
+
+    assert!(calendar.view_mode == ViewMode::Month);
+    calendar.on_event(Event::Key(Key::Enter));
+    assert!(calendar.view_mode == ViewMode::Week);
+
+    calendar.on_event(Event::Key(Key::Down));
+    assert_eq!(Utc.ymd(2017, 8, 2), calendar.view_date);
+
+    // Left and right stop at the edges of the week
+    for _ in 0..5 {
+        calendar.on_event(Event::Key(Key::Right));
+    }
+    assert_eq!(Utc.ymd(2017, 8, 6), calendar.view_date);
+    for _ in 0..8 {
+        calendar.on_event(Event::Key(Key::Left));
+    }
+    assert_eq!(Utc.ymd(2017, 7, 31), calendar.view_date);
+
+    calendar.set_view_date(Utc.ymd(2017, 8, 3));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(Utc.ymd(2017, 7, 31), calendar.date);
+    assert_eq!(
+        ColorStyle::highlight_inactive(),
+        calendar.cell_color(&Utc.ymd(2017, 8, 6), false)
+    );
+
+    // Weeks spanning two months are highlighted in the adjacent month too
+    assert_eq!(
+        ColorStyle::highlight_inactive(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 31), false)
+    );
+    assert_eq!(
+        ColorStyle::highlight(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 31), true)
+    );
+    assert_eq!(
+        ColorStyle::secondary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 30), true)
+    );
+
+    calendar.on_event(Event::Key(Key::Backspace));
+    assert!(calendar.view_mode == ViewMode::Month);
+}
+
+#[test]
+fn test_multi_select() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .multi_select(true)
+        .on_submit(|_, _| panic!("Submit must not be called in multi select mode"));
+
+    calendar.on_event(Event::Key(Key::Enter));
+    calendar.on_event(Event::Key(Key::Left));
+    calendar.on_event(Event::Key(Key::Enter));
+    calendar.on_event(Event::Key(Key::Down));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(
+        vec![
+            Utc.ymd(2017, 7, 25),
+            Utc.ymd(2017, 7, 26),
+            Utc.ymd(2017, 8, 1)
+        ],
+        calendar.selected_dates()
+    );
+
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(
+        vec![Utc.ymd(2017, 7, 25), Utc.ymd(2017, 7, 26)],
+        calendar.selected_dates()
+    );
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.date);
+
+    calendar.on_event(Event::Key(Key::Up));
+    assert_eq!(
+        ColorStyle::highlight_inactive(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 26), false)
+    );
+    assert_eq!(
+        ColorStyle::primary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 24), false)
+    );
+}
+
+#[test]
+fn test_multi_select_markers() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .multi_select(true)
+        .monochrome_markers(true)
+        .view_date(Utc.ymd(2017, 7, 3));
+
+    calendar.on_event(Event::Key(Key::Enter));
+    calendar.on_event(Event::Key(Key::Right));
+    calendar.on_event(Event::Key(Key::Right));
+    calendar.on_event(Event::Key(Key::Enter));
+    calendar.on_event(Event::Key(Key::Down));
+
+    // Both selected days are marked, the single selection date is not
+    assert!(calendar.date_selected(&Utc.ymd(2017, 7, 3)));
+    assert!(calendar.date_selected(&Utc.ymd(2017, 7, 5)));
+    assert!(!calendar.date_selected(&Utc.ymd(2017, 7, 26)));
+}
+
+#[test]
+fn test_required_size_long_month_names() {
+    struct VerboseLocale;
+    impl Locale for VerboseLocale {
+        fn week_day(day: WeekDay, long_text: bool) -> &'static str {
+            EnglishLocale::week_day(day, long_text)
+        }
+
+        fn month(month: Month, long_text: bool) -> &'static str {
+            if long_text && month == Month::September {
+                "Septembermonth of harvest"
+            } else {
+                EnglishLocale::month(month, long_text)
+            }
+        }
+    }
+
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2024, 1, 1));
+    assert_eq!(Vec2::new(20, 8), calendar.required_size(Vec2::zero()));
+
+    let mut calendar = CalendarView::<Utc, VerboseLocale>::new(Utc.ymd(2024, 1, 1));
+    assert_eq!(Vec2::new(30, 8), calendar.required_size(Vec2::zero()));
+}
+
+#[test]
+#[cfg(feature = "locale-fr")]
+fn test_year_grid_long_short_month_names() {
+    let mut calendar =
+        CalendarView::<Utc, FrenchLocale>::new(Utc.ymd(2017, 7, 26)).view_mode(ViewMode::Year);
+    let click = |x, y| Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(x, y),
+        event: MouseEvent::Press(MouseButton::Left),
+    };
+
+    // "janv.", "sept." etc. need five columns plus a gap
+    assert_eq!(6, calendar.year_cell_width());
+    assert_eq!(Vec2::new(23, 8), calendar.required_size(Vec2::zero()));
+
+    calendar.on_event(click(22, 6));
+    assert_eq!(Utc.ymd(2017, 12, 26), calendar.view_date);
+
+    calendar.on_event(click(5, 2));
+    assert_eq!(Utc.ymd(2017, 12, 26), calendar.view_date);
+
+    calendar.on_event(click(6, 2));
+    assert_eq!(Utc.ymd(2017, 2, 26), calendar.view_date);
+}
+
+#[test]
+fn test_year_locked() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 2, 26))
+        .view_mode(ViewMode::Year)
+        .year_locked(true);
+
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(2017, 2, 26), calendar.view_date);
+
+    calendar.on_event(Event::Key(Key::Up));
+    assert_eq!(Utc.ymd(2017, 2, 26), calendar.view_date);
+
+    calendar.on_event(Event::Key(Key::Down));
+    assert_eq!(Utc.ymd(2017, 6, 26), calendar.view_date);
+
+    calendar.set_year_locked(false);
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(2018, 6, 26), calendar.view_date);
+}
+
+#[test]
+fn test_range_select() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .range_select(true)
+        .on_submit(|_, _| panic!("Submit must not be called in range select mode"));
+
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(None, calendar.selected_range());
+
+    calendar.on_event(Event::Key(Key::Down));
+    assert_eq!(
+        Some((Utc.ymd(2017, 7, 26), Utc.ymd(2017, 8, 2))),
+        calendar.highlighted_range()
+    );
+
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(
+        Some((Utc.ymd(2017, 7, 26), Utc.ymd(2017, 8, 2))),
+        calendar.selected_range()
+    );
+
+    // Adjacent month days within the range are highlighted as well
+    assert_eq!(
+        ColorStyle::highlight_inactive(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 31), false)
+    );
+    assert_eq!(
+        ColorStyle::highlight(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 26), false)
+    );
+
+    // Ends before the anchor are swapped
+    calendar.on_event(Event::Key(Key::Enter));
+    calendar.on_event(Event::Key(Key::Up));
+    calendar.on_event(Event::Key(Key::Up));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(
+        Some((Utc.ymd(2017, 7, 19), Utc.ymd(2017, 8, 2))),
+        calendar.selected_range()
+    );
+}
+
+#[test]
+fn test_day_decorator() {
+    let red = ColorStyle::front(Color::Rgb(255, 0, 0));
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .earliest_date(Some(Utc.ymd(2017, 7, 3)))
+        .day_decorator(move |date| {
+            assert!(*date >= Utc.ymd(2017, 7, 3));
+            Some(red)
+        });
+
+    assert_eq!(red, calendar.cell_color(&Utc.ymd(2017, 7, 4), false));
+    assert_eq!(
+        ColorStyle::highlight_inactive(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 26), false)
+    );
+    assert_eq!(
+        ColorStyle::tertiary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 2), false)
+    );
+}
+
+#[test]
+fn test_editing() {
+    let calendar =
+        CalendarView::<Utc, EnglishLocale>::editing(Utc.ymd(2017, 7, 26), Utc.ymd(1969, 7, 20));
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.today());
+    assert_eq!(Utc.ymd(1969, 7, 20), calendar.date());
+    assert_eq!(Utc.ymd(1969, 7, 20), calendar.get_view_date());
+    assert!(calendar.get_view_mode() == ViewMode::Month);
+}
+
+#[test]
+fn test_naive_calendar_view() {
+    let mut calendar =
+        NaiveCalendarView::<EnglishLocale>::new(NaiveDate::from_ymd_opt(2017, 7, 26).unwrap())
+            .earliest_date(NaiveDate::from_ymd_opt(2017, 7, 10));
+
+    calendar.set_selected_date(NaiveDate::from_ymd_opt(2017, 7, 1).unwrap());
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2017, 7, 10).unwrap(),
+        calendar.date()
+    );
+
+    calendar.on_event(Event::Key(Key::Right));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2017, 7, 27).unwrap(),
+        calendar.date()
+    );
+    assert_eq!(Utc.ymd(2017, 7, 27), calendar.inner().date());
+}
+
+#[test]
+fn test_on_mode_locked() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::current_month_only(Utc.ymd(2017, 7, 26))
+        .on_mode_locked(|_| {});
+
+    match calendar.on_event(Event::Key(Key::Backspace)) {
+        EventResult::Consumed(Some(_)) => {}
+        _ => panic!("Expected the mode locked callback"),
+    }
+
+    calendar.set_highest_view_mode(ViewMode::Year);
+    match calendar.on_event(Event::Key(Key::Backspace)) {
+        EventResult::Consumed(None) => {}
+        _ => panic!("Expected a successful mode switch"),
+    }
+    assert!(calendar.view_mode == ViewMode::Year);
+}
+
+#[test]
+fn test_highlight_today_week() {
+    // July 26th 2017 is a Wednesday
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .view_date(Utc.ymd(2017, 7, 1))
+        .highlight_today_week(true);
+
+    assert_eq!(
+        ColorStyle::title_secondary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 24), false)
+    );
+    assert_eq!(
+        ColorStyle::title_secondary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 30), false)
+    );
+    assert_eq!(
+        ColorStyle::primary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 23), false)
+    );
+
+    calendar.set_week_start(WeekDay::Sunday);
+    assert_eq!(
+        ColorStyle::title_secondary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 23), false)
+    );
+    assert_eq!(
+        ColorStyle::primary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 30), false)
+    );
+}
+
+#[cfg(all(
+    test,
+    any(feature = "locale-de", feature = "locale-fr", feature = "locale-es")
+))]
+fn assert_locale_complete<L: Locale>() {
+    for index in 0..12 {
+        let month = Month::from(index);
+        assert!(!L::month(month, true).is_empty());
+        assert!(!L::month(month, false).is_empty());
+    }
+    for index in 0..7 {
+        let day = WeekDay::from(index);
+        assert!(!L::week_day(day, true).is_empty());
+        assert!(!L::week_day(day, false).is_empty());
+    }
+}
+
+#[test]
+#[cfg(any(feature = "locale-de", feature = "locale-fr", feature = "locale-es"))]
+fn test_bundled_locales() {
+    #[cfg(feature = "locale-de")]
+    {
+        assert_locale_complete::<GermanLocale>();
+        assert_eq!("Mi", GermanLocale::week_day(WeekDay::Wednesday, false));
+        assert_eq!("März", GermanLocale::month(Month::March, true));
+    }
+    #[cfg(feature = "locale-fr")]
+    {
+        assert_locale_complete::<FrenchLocale>();
+        assert_eq!("févr.", FrenchLocale::month(Month::February, false));
+    }
+    #[cfg(feature = "locale-es")]
+    {
+        assert_locale_complete::<SpanishLocale>();
+        assert_eq!("sábado", SpanishLocale::week_day(WeekDay::Saturday, true));
+    }
+}
+
+#[test]
+fn test_weekday_header_width() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    let default_size = calendar.required_size(Vec2::new(80, 24));
+    assert_eq!("Tu", calendar.week_day_header(WeekDay::Tuesday));
+
+    calendar.set_weekday_header_width(1);
+    assert_eq!("T", calendar.week_day_header(WeekDay::Tuesday));
+    assert_eq!(default_size, calendar.required_size(Vec2::new(80, 24)));
+
+    calendar.set_weekday_header_width(3);
+    assert_eq!("Tue", calendar.week_day_header(WeekDay::Tuesday));
+    assert_eq!(4, calendar.grid_cell_width());
+    assert!(calendar.required_size(Vec2::new(80, 24)).x > default_size.x);
+    assert_eq!("T", EnglishLocale::week_day_narrow(WeekDay::Thursday));
+
+    calendar.set_weekday_header_width(0);
+    assert_eq!("T", calendar.week_day_header(WeekDay::Tuesday));
+
+    calendar.set_weekday_header_width(8);
+    assert_eq!("Tue", calendar.week_day_header(WeekDay::Tuesday));
+}
+
+#[test]
+fn test_on_view_mode_change() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .on_view_mode_change(|_, _| {});
+
+    match calendar.on_event(Event::Key(Key::Backspace)) {
+        EventResult::Consumed(Some(_)) => {}
+        _ => panic!("Expected a view mode change callback"),
+    }
+    assert!(calendar.view_mode == ViewMode::Year);
+
+    match calendar.on_event(Event::Key(Key::Right)) {
+        EventResult::Consumed(None) => {}
+        _ => panic!("Expected no view mode change callback"),
+    }
+
+    match calendar.on_event(Event::Key(Key::Enter)) {
+        EventResult::Consumed(Some(_)) => {}
+        _ => panic!("Expected a view mode change callback"),
+    }
+    assert!(calendar.view_mode == ViewMode::Month);
+}
+
+#[test]
+fn test_today_key() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .view_date(Utc.ymd(2020, 3, 1))
+        .on_select(|_, _| {});
+
+    match calendar.on_event(Event::Char('t')) {
+        EventResult::Consumed(Some(_)) => {}
+        _ => panic!("Expected a select callback"),
+    }
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.view_date);
+
+    calendar.set_earliest_date(Some(Utc.ymd(2018, 1, 1)));
+    calendar.on_event(Event::Char('t'));
+    assert_eq!(Utc.ymd(2018, 1, 1), calendar.view_date);
+
+    calendar.set_today_key(Some(Event::Key(Key::Home)));
+    calendar.set_view_date(Utc.ymd(2020, 3, 1));
+    match calendar.on_event(Event::Char('t')) {
+        EventResult::Ignored => {}
+        _ => panic!("Expected the event to be ignored"),
+    }
+    calendar.on_event(Event::Key(Key::Home));
+    assert_eq!(Utc.ymd(2018, 1, 1), calendar.view_date);
+}
+
+#[test]
+fn test_today_key_bound() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .view_date(Utc.ymd(2020, 3, 1))
+        .key_bindings(KeyBindings::default().binding(Event::Char('t'), KeyAction::MoveNext));
+
+    calendar.on_event(Event::Char('t'));
+    assert_eq!(Utc.ymd(2020, 3, 2), calendar.view_date);
+}
+
+#[test]
+fn test_today_key_unavailable() {
+    // Saturday
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 29))
+        .view_date(Utc.ymd(2017, 7, 3))
+        .business_days_only(true);
+
+    calendar.on_event(Event::Char('t'));
+    assert_eq!(Utc.ymd(2017, 7, 28), calendar.view_date);
+
+    calendar.set_business_days_only(false);
+    calendar.set_disabled_week_days(vec![WeekDay::Saturday, WeekDay::Friday]);
+    calendar.set_skip_disabled_on_nav(true);
+    calendar.set_view_date(Utc.ymd(2017, 7, 3));
+    calendar.on_event(Event::Char('t'));
+    assert_eq!(Utc.ymd(2017, 7, 30), calendar.view_date);
+}
+
+#[test]
+fn test_today_key_year_locked() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2016, 2, 29))
+        .view_date(Utc.ymd(2017, 9, 1))
+        .view_mode(ViewMode::Year)
+        .year_locked(true);
+
+    calendar.on_event(Event::Char('t'));
+    assert_eq!(Utc.ymd(2017, 2, 28), calendar.view_date);
+
+    // Other view modes are not locked
+    calendar.set_view_mode(ViewMode::Month);
+    calendar.on_event(Event::Char('t'));
+    assert_eq!(Utc.ymd(2016, 2, 29), calendar.view_date);
+}
+
+#[test]
+fn test_key_bindings() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26)).key_bindings(
+        KeyBindings::default()
+            .binding(Event::Char('h'), KeyAction::MovePrev)
+            .binding(Event::Char('l'), KeyAction::MoveNext)
+            .binding(Event::Char(' '), KeyAction::Submit),
+    );
+
+    calendar.on_event(Event::Char('l'));
+    assert_eq!(Utc.ymd(2017, 7, 27), calendar.view_date);
+    calendar.on_event(Event::Key(Key::Left));
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.view_date);
+    calendar.on_event(Event::Char('h'));
+    assert_eq!(Utc.ymd(2017, 7, 25), calendar.view_date);
+
+    calendar.on_event(Event::Char(' '));
+    assert_eq!(Utc.ymd(2017, 7, 25), calendar.date);
+
+    let mut bindings = KeyBindings::default();
+    bindings.unbind(&Event::Key(Key::Backspace));
+    calendar.set_key_bindings(bindings);
+    match calendar.on_event(Event::Key(Key::Backspace)) {
+        EventResult::Ignored => {}
+        _ => panic!("Expected the event to be ignored"),
+    }
+    assert!(calendar.view_mode == ViewMode::Month);
+}
+
+#[test]
+fn test_persisted_state() {
+    let calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .earliest_date(Some(Utc.ymd(2017, 1, 1)))
+        .latest_date(Some(Utc.ymd(2017, 12, 31)))
+        .selected_date(Utc.ymd(2017, 3, 4))
+        .view_date(Utc.ymd(2017, 5, 6))
+        .view_mode(ViewMode::Year)
+        .week_start(WeekDay::Sunday)
+        .show_iso_weeks(true);
+
+    let state = calendar.persisted_state();
+    assert_eq!(NaiveDate::from_ymd(2017, 3, 4), state.date);
+    assert_eq!(Some(NaiveDate::from_ymd(2017, 1, 1)), state.earliest_date);
+
+    let mut restored = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2020, 1, 1));
+    restored.apply_state(&state);
+    assert_eq!(Utc.ymd(2017, 3, 4), restored.date);
+    assert_eq!(Utc.ymd(2017, 5, 6), restored.view_date);
+    assert_eq!(Some(Utc.ymd(2017, 1, 1)), restored.earliest_date);
+    assert_eq!(Some(Utc.ymd(2017, 12, 31)), restored.latest_date);
+    assert!(restored.view_mode == ViewMode::Year);
+    assert_eq!(WeekDay::Sunday, restored.week_start);
+    assert!(restored.show_iso_weeks);
+}
+
+#[test]
+fn test_mouse_outside_grid() {
+    let mut calendar =
+        CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26)).show_iso_weeks(true);
+    let click = |x, y| Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(x, y),
+        event: MouseEvent::Press(MouseButton::Left),
+    };
+
+    // ISO week gutter
+    calendar.on_event(click(1, 3));
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.view_date);
+
+    // Right of the last column
+    calendar.on_event(click(3 + 7 * 3, 3));
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.view_date);
+
+    // Below the last row
+    calendar.on_event(click(3, 8));
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.view_date);
+
+    calendar.set_view_mode(ViewMode::Year);
+    calendar.on_event(click(2 + 4 * 5, 2));
+    calendar.on_event(click(2, 8));
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.view_date);
+
+    calendar.set_view_mode(ViewMode::Decade);
+    calendar.on_event(click(2 + 4 * 5, 2));
+    calendar.on_event(click(2 + 5, 8));
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.view_date);
+}
+
+#[test]
+fn test_month_week_day_ordering() {
+    assert_eq!(Month::July, Month::from(6));
+    assert_eq!(WeekDay::Sunday, WeekDay::from(6));
+    assert!(Month::January < Month::December);
+    assert!(WeekDay::Monday < WeekDay::Sunday);
+
+    use std::collections::HashMap;
+
+    let mut events = HashMap::new();
+    events.insert(Month::March, 2);
+    assert_eq!(Some(&2), events.get(&Month::March));
+}
+
+#[test]
+fn test_month_week_day_iteration() {
+    assert_eq!(12, Month::all().count());
+    assert_eq!(Some(Month::December), Month::all().last());
+    assert_eq!(Month::January, Month::December.succ());
+    assert_eq!(Month::December, Month::January.prev());
+
+    assert_eq!(7, WeekDay::all().count());
+    assert_eq!(Some(WeekDay::Monday), WeekDay::all().next());
+    assert_eq!(WeekDay::Monday, WeekDay::Sunday.next());
+    assert_eq!(WeekDay::Sunday, WeekDay::Monday.prev());
+}
+
+#[test]
+fn test_month_week_day_display() {
+    assert_eq!("March", format!("{}", Month::March));
+    assert_eq!("Mar", format!("{:#}", Month::March));
+    assert_eq!("Thursday", format!("{}", WeekDay::Thursday));
+    assert_eq!("Th", format!("{:#}", WeekDay::Thursday));
+}
+
+#[test]
+fn test_fixed_height() {
+    // February 2016 starts on a Monday and spans five weeks
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2016, 2, 1));
+    assert_eq!(8, calendar.required_size(Vec2::zero()).y);
+
+    calendar.set_fixed_height(false);
+    assert_eq!(7, calendar.required_size(Vec2::zero()).y);
+
+    // February 2015 starts on a Sunday and spans four weeks
+    calendar.set_view_date(Utc.ymd(2015, 2, 1));
+    calendar.set_week_start(WeekDay::Sunday);
+    assert!(calendar.needs_relayout());
+    assert_eq!(6, calendar.required_size(Vec2::zero()).y);
+
+    calendar.set_view_mode(ViewMode::Year);
+    assert_eq!(8, calendar.required_size(Vec2::zero()).y);
+}
+
+#[test]
+fn test_show_adjacent_days() {
+    // July 2017 starts on a Saturday, so the first row begins with June 26th
+    let mut calendar =
+        CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26)).show_adjacent_days(false);
+    let click = |x, y| Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(x, y),
+        event: MouseEvent::Press(MouseButton::Left),
+    };
+
+    match calendar.on_event(click(0, 2)) {
+        EventResult::Ignored => {}
+        _ => panic!("Expected the click to be ignored"),
+    }
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.view_date);
+
+    calendar.set_show_adjacent_days(true);
+    calendar.on_event(click(0, 2));
+    assert_eq!(Utc.ymd(2017, 6, 26), calendar.view_date);
+}
+
+#[test]
+fn test_on_boundary() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .earliest_date(Some(Utc.ymd(2017, 7, 1)))
+        .latest_date(Some(Utc.ymd(2017, 7, 31)))
+        .on_boundary(|_, _| {});
+
+    assert_eq!(
+        Some(Boundary::Earliest),
+        calendar.exceeded_boundary(&Utc.ymd(2017, 6, 30))
+    );
+    assert_eq!(
+        Some(Boundary::Latest),
+        calendar.exceeded_boundary(&Utc.ymd(2017, 8, 1))
+    );
+    assert_eq!(None, calendar.exceeded_boundary(&Utc.ymd(2017, 7, 1)));
+
+    match calendar.on_event(Event::Key(Key::Up)) {
+        EventResult::Consumed(None) => {}
+        _ => panic!("Expected no boundary callback"),
+    }
+
+    calendar.set_view_date(Utc.ymd(2017, 7, 1));
+    match calendar.on_event(Event::Key(Key::Left)) {
+        EventResult::Consumed(Some(_)) => {}
+        _ => panic!("Expected a boundary callback"),
+    }
+    assert_eq!(Utc.ymd(2017, 7, 1), calendar.view_date);
+
+    calendar.set_view_mode(ViewMode::Year);
+    match calendar.on_event(Event::Key(Key::Right)) {
+        EventResult::Consumed(Some(_)) => {}
+        _ => panic!("Expected a boundary callback"),
+    }
+    assert_eq!(Utc.ymd(2017, 7, 31), calendar.view_date);
+}
+
+#[test]
+fn test_date_time_calendar_view() {
+    let mut view = DateTimeCalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .time(NaiveTime::from_hms_opt(9, 30, 15).unwrap())
+        .on_submit(|_, _| {});
+    assert_eq!(Vec2::new(20, 10), view.required_size(Vec2::new(80, 24)));
+
+    view.on_event(Event::Key(Key::Right));
+    assert!(!view.is_editing_time());
+    view.on_event(Event::Key(Key::Enter));
+    assert!(view.is_editing_time());
+    assert_eq!(Utc.ymd(2017, 7, 27), view.inner().date());
+
+    view.on_event(Event::Key(Key::Down));
+    view.on_event(Event::Key(Key::Right));
+    view.on_event(Event::Key(Key::Up));
+    match view.on_event(Event::Key(Key::Enter)) {
+        EventResult::Consumed(Some(_)) => {}
+        _ => panic!("Expected a submit callback"),
+    }
+    assert!(!view.is_editing_time());
+    assert_eq!(
+        Some(Utc.ymd(2017, 7, 27).and_hms(8, 31, 0)),
+        view.date_time()
+    );
+}
+
+#[test]
+fn test_event_dates() {
+    let mut events = HashSet::new();
+    events.insert(Utc.ymd(2017, 7, 3));
+    events.insert(Utc.ymd(2017, 7, 26));
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .event_dates(events)
+        .event_marker('*');
+    assert_eq!(2, calendar.get_event_dates().len());
+
+    assert_eq!("*3", calendar.cell_label(&Utc.ymd(2017, 7, 3), "3", 2));
+    assert_eq!(" 4", calendar.cell_label(&Utc.ymd(2017, 7, 4), "4", 2));
+    assert_eq!("26", calendar.cell_label(&Utc.ymd(2017, 7, 26), "26", 2));
+    assert_eq!("*26", calendar.cell_label(&Utc.ymd(2017, 7, 26), "26", 3));
+
+    calendar.set_event_dates(HashSet::new());
+    assert_eq!(" 3", calendar.cell_label(&Utc.ymd(2017, 7, 3), "3", 2));
+}
+
+#[test]
+fn test_marked_dates() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    assert!(calendar.marked_dates().is_empty());
+
+    calendar.set_event_dates(
+        vec![Utc.ymd(2017, 7, 26), Utc.ymd(2017, 7, 3)]
+            .into_iter()
+            .collect(),
+    );
+    assert_eq!(
+        vec![Utc.ymd(2017, 7, 3), Utc.ymd(2017, 7, 26)],
+        calendar.marked_dates()
+    );
+
+    calendar.set_disabled_week_days(vec![WeekDay::Sunday, WeekDay::Saturday]);
+    assert_eq!(
+        &[WeekDay::Sunday, WeekDay::Saturday],
+        calendar.get_disabled_week_days()
+    );
+}
+
+#[test]
+fn test_click_adjacent_day() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    let click = |x, y| Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(x, y),
+        event: MouseEvent::Press(MouseButton::Left),
+    };
+
+    // August 2nd is drawn in the last row of July
+    calendar.on_event(click(6, 7));
+    assert_eq!(Utc.ymd(2017, 8, 2), calendar.view_date);
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.date);
+
+    // ...and in the first row of August, where a second click selects it
+    calendar.on_event(click(6, 2));
+    assert_eq!(Utc.ymd(2017, 8, 2), calendar.date);
+}
+
+#[test]
+fn test_click_adjacent_centers() {
+    let click = |x, y| Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(x, y),
+        event: MouseEvent::Press(MouseButton::Left),
+    };
+
+    // By default, clicks onto unavailable adjacent days are clamped
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .latest_date(Some(Utc.ymd(2017, 8, 1)));
+    calendar.on_event(click(6, 7));
+    assert_eq!(Utc.ymd(2017, 8, 1), calendar.view_date);
+
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .latest_date(Some(Utc.ymd(2017, 8, 1)))
+        .click_adjacent_centers(true);
+    calendar.on_event(click(6, 7));
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.view_date);
+
+    // Available adjacent days place the cursor onto the clicked day
+    calendar.on_event(click(3, 7));
+    assert_eq!(Utc.ymd(2017, 8, 1), calendar.view_date);
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.date);
+}
+
+#[test]
+fn test_fast_drill() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .view_mode(ViewMode::Decade)
+        .fast_drill(true);
+
+    calendar.on_event(Event::Key(Key::Enter));
+    assert!(calendar.view_mode == ViewMode::Month);
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.view_date);
+
+    calendar.set_lowest_view_mode(ViewMode::Week);
+    calendar.set_view_mode(ViewMode::Decade);
+    calendar.on_event(Event::Key(Key::Enter));
+    assert!(calendar.view_mode == ViewMode::Week);
+
+    calendar.set_fast_drill(false);
+    calendar.set_view_mode(ViewMode::Decade);
+    calendar.on_event(Event::Key(Key::Enter));
+    assert!(calendar.view_mode == ViewMode::Year);
+}
+
+#[test]
+fn test_emphasize_available() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .view_date(Utc.ymd(2017, 7, 20))
+        .disabled_week_days(vec![WeekDay::Sunday]);
+    calendar.set_today(Utc.ymd(2017, 7, 3));
+    assert_eq!(Effect::Simple, calendar.cell_effect(&Utc.ymd(2017, 7, 21)));
+
+    calendar.set_emphasize_available(true);
+    assert_eq!(Effect::Bold, calendar.cell_effect(&Utc.ymd(2017, 7, 21)));
+    assert_eq!(Effect::Bold, calendar.cell_effect(&Utc.ymd(2017, 7, 3)));
+
+    // Unavailable days, adjacent days, the cursor and the selected date
+    assert_eq!(Effect::Simple, calendar.cell_effect(&Utc.ymd(2017, 7, 23)));
+    assert_eq!(Effect::Simple, calendar.cell_effect(&Utc.ymd(2017, 8, 1)));
+    assert_eq!(Effect::Simple, calendar.cell_effect(&Utc.ymd(2017, 7, 20)));
+    assert_eq!(Effect::Simple, calendar.cell_effect(&Utc.ymd(2017, 7, 26)));
+
+    calendar.set_latest_date(Some(Utc.ymd(2017, 7, 28)));
+    assert_eq!(Effect::Simple, calendar.cell_effect(&Utc.ymd(2017, 7, 29)));
+}
+
+#[test]
+fn test_click_selects_any() {
+    let click = |x, y| Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(x, y),
+        event: MouseEvent::Press(MouseButton::Left),
+    };
+
+    // July 12th is drawn in the third row, August 2nd in the last one
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    calendar.on_event(click(6, 4));
+    assert_eq!(Utc.ymd(2017, 7, 12), calendar.view_date);
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.date);
+
+    let mut calendar =
+        CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26)).click_selects_any(true);
+    calendar.on_event(click(6, 4));
+    assert_eq!(Utc.ymd(2017, 7, 12), calendar.view_date);
+    assert_eq!(Utc.ymd(2017, 7, 12), calendar.date);
+
+    // Adjacent days only navigate, with the option on and off
+    calendar.on_event(click(6, 7));
+    assert_eq!(Utc.ymd(2017, 8, 2), calendar.view_date);
+    assert_eq!(Utc.ymd(2017, 7, 12), calendar.date);
+
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    calendar.on_event(click(6, 7));
+    assert_eq!(Utc.ymd(2017, 8, 2), calendar.view_date);
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.date);
+}
+
+#[test]
+fn test_rtl() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26)).rtl(true);
+    let click = |x, y| Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(x, y),
+        event: MouseEvent::Press(MouseButton::Left),
+    };
+
+    // The first row starts with Monday, June 26th in the rightmost column
+    calendar.on_event(click(18, 2));
+    assert_eq!(Utc.ymd(2017, 6, 26), calendar.view_date);
+
+    calendar.set_view_mode(ViewMode::Year);
+    calendar.on_event(click(0, 2));
+    assert_eq!(Utc.ymd(2017, 4, 26), calendar.view_date);
+
+    calendar.set_view_mode(ViewMode::Decade);
+    calendar.on_event(click(15, 2));
+    assert_eq!(Utc.ymd(2009, 4, 26), calendar.view_date);
+
+    // ISO weeks are drawn right of the grid without shifting it
+    let ltr_size = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .show_iso_weeks(true)
+        .required_size(Vec2::zero());
+    calendar.set_show_iso_weeks(true);
+    calendar.set_view_mode(ViewMode::Month);
+    assert_eq!(0, calendar.h_offset(ViewMode::Month));
+    assert_eq!(21, calendar.iso_week_x());
+    assert_eq!(ltr_size, calendar.required_size(Vec2::zero()));
+    assert_eq!(23, ltr_size.x);
+
+    calendar.set_view_date(Utc.ymd(2017, 7, 26));
+    calendar.on_event(click(18, 2));
+    assert_eq!(Utc.ymd(2017, 6, 26), calendar.view_date);
+}
+
+#[test]
+fn test_header_formatter() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    assert_eq!("July 2017", calendar.header_title());
+    calendar.set_view_mode(ViewMode::Year);
+    assert_eq!("2017", calendar.header_title());
+    calendar.set_view_mode(ViewMode::Decade);
+    assert_eq!("2010 - 2019", calendar.header_title());
+
+    calendar.set_header_formatter(|mode, date| match mode {
+        ViewMode::Month => Some(format!("{}年{}月", date.year(), date.month())),
+        _ => None,
+    });
+    assert_eq!("2010 - 2019", calendar.header_title());
+    calendar.set_view_mode(ViewMode::Month);
+    assert_eq!("2017年7月", calendar.header_title());
+}
+
+#[test]
+fn test_builder_and_clone() {
+    let builder = CalendarView::<Utc, EnglishLocale>::builder()
+        .highest_view_mode(ViewMode::Year)
+        .earliest_date(Some(Utc.ymd(2017, 1, 1)))
+        .latest_date(Some(Utc.ymd(2017, 12, 31)))
+        .option(|v| v.set_cell_width(3));
+
+    let a = builder.build(Utc.ymd(2017, 7, 26)).show_iso_weeks(true);
+    let b = builder.build(Utc.ymd(2017, 7, 26));
+    assert!(a.highest_view_mode == ViewMode::Year);
+    assert_eq!(Some(Utc.ymd(2017, 12, 31)), b.latest_date);
+    assert_eq!(3, b.cell_width);
+    assert!(a.show_iso_weeks && !b.show_iso_weeks);
+
+    let mut c = a.clone();
+    c.set_selected_date(Utc.ymd(2017, 3, 1));
+    assert_eq!(Utc.ymd(2017, 7, 26), a.date);
+    assert_eq!(Utc.ymd(2017, 3, 1), c.date);
+    assert!(c.show_iso_weeks);
+}
+
+#[test]
+fn test_builder_typed_options() {
+    let builder = CalendarView::<Utc, EnglishLocale>::builder()
+        .date_range(Utc.ymd(2017, 7, 1)..Utc.ymd(2017, 8, 1))
+        .style(CalendarStyle::default())
+        .key_bindings(KeyBindings::default().binding(Event::Char('l'), KeyAction::MoveNext))
+        .on_select(|_, _| {});
+
+    let mut calendar = builder.build(Utc.ymd(2017, 7, 26));
+    assert_eq!(Some(Utc.ymd(2017, 7, 1)), calendar.earliest_date);
+    assert_eq!(Some(Utc.ymd(2017, 7, 31)), calendar.latest_date);
+
+    match calendar.on_event(Event::Char('l')) {
+        EventResult::Consumed(Some(_)) => {}
+        _ => panic!("Expected a select callback"),
+    }
+    assert_eq!(Utc.ymd(2017, 7, 27), calendar.view_date);
+}
+
+#[test]
+fn test_navigation_across_year_zero() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(1, 1, 15));
+    calendar.on_event(Event::Key(Key::PageUp));
+    assert_eq!(Utc.ymd(0, 12, 15), calendar.view_date);
+    calendar.on_event(Event::Key(Key::PageUp));
+    assert_eq!(Utc.ymd(0, 11, 15), calendar.view_date);
+
+    calendar.set_view_date(Utc.ymd(-1, 3, 1));
+    calendar.on_event(Event::Key(Key::Left));
+    assert_eq!(Utc.ymd(-1, 2, 28), calendar.view_date);
+
+    calendar.set_view_mode(ViewMode::Year);
+    calendar.set_view_date(Utc.ymd(1, 2, 28));
+    calendar.on_event(Event::Key(Key::PageUp));
+    assert_eq!(Utc.ymd(0, 2, 28), calendar.view_date);
+
+    calendar.set_view_mode(ViewMode::Decade);
+    calendar.set_view_date(Utc.ymd(3, 6, 1));
+    calendar.on_event(Event::Key(Key::PageUp));
+    assert_eq!(Utc.ymd(-7, 6, 1), calendar.view_date);
+
+    calendar.set_view_date(Utc.from_utc_date(&NaiveDate::MIN.with_month(6).unwrap()));
+    calendar.on_event(Event::Key(Key::PageUp));
+    assert_eq!(Utc.from_utc_date(&NaiveDate::MIN), calendar.view_date);
+
+    calendar.set_view_mode(ViewMode::Month);
+    calendar.set_view_date(Utc.from_utc_date(&NaiveDate::MAX.with_day(28).unwrap()));
+    calendar.on_event(Event::Key(Key::Down));
+    assert_eq!(Utc.from_utc_date(&NaiveDate::MAX), calendar.view_date);
+
+    // Only targets outside of chrono's range are clamped
+    type View = CalendarView<Utc, EnglishLocale>;
+    let min = Utc.from_utc_date(&NaiveDate::MIN);
+    let max = Utc.from_utc_date(&NaiveDate::MAX);
+    assert_eq!(
+        None,
+        View::navigation_limit(&Utc.ymd(2017, 7, 26), (0, 1, 0))
+    );
+    assert_eq!(
+        None,
+        View::navigation_limit(&Utc.ymd(2017, 7, 26), (-7, 0, 0))
+    );
+    assert_eq!(None, View::navigation_limit(&min, (0, 11, 0)));
+    assert_eq!(Some(min), View::navigation_limit(&min, (0, -1, 0)));
+    assert_eq!(Some(min), View::navigation_limit(&min, (-1, 0, 0)));
+    assert_eq!(Some(max), View::navigation_limit(&max, (0, 0, 1)));
+    assert_eq!(Some(max), View::navigation_limit(&max, (7, 0, 0)));
+}
+
+#[test]
+fn test_month_grid() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    let grid = calendar.month_grid();
+    assert_eq!(6, grid.len());
+    assert_eq!(Some(Utc.ymd(2017, 6, 26)), grid[0][0]);
+    assert_eq!(Some(Utc.ymd(2017, 7, 1)), grid[0][5]);
+    assert_eq!(Some(Utc.ymd(2017, 8, 6)), grid[5][6]);
+
+    calendar.set_fixed_height(false);
+    calendar.set_show_adjacent_days(false);
+    let grid = calendar.month_grid();
+    assert_eq!(6, grid.len());
+    assert_eq!(None, grid[0][0]);
+    assert_eq!(Some(Utc.ymd(2017, 7, 31)), grid[5][0]);
+    assert_eq!(None, grid[5][1]);
+
+    calendar.set_view_date(Utc.ymd(2017, 6, 1));
+    assert_eq!(5, calendar.month_grid().len());
+}
+
+#[test]
+fn test_style() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .view_date(Utc.ymd(2017, 7, 10));
+    assert_eq!(CalendarStyle::default(), calendar.get_style());
+
+    let style = CalendarStyle {
+        cursor: ColorStyle::title_primary(),
+        normal: ColorStyle::secondary(),
+        ..CalendarStyle::default()
+    };
+    calendar.set_style(style);
+    assert_eq!(
+        ColorStyle::title_primary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 10), true)
+    );
+    assert_eq!(
+        ColorStyle::secondary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 11), true)
+    );
+
+    calendar.set_today_style(ColorStyle::tertiary());
+    assert_eq!(ColorStyle::tertiary(), calendar.get_style().today);
+}
+
+#[test]
+fn test_selected_iso_week() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    assert_eq!((2017, 30), calendar.selected_iso_week());
+    assert_eq!(WeekDay::Wednesday, calendar.selected_weekday());
+
+    calendar.set_selected_date(Utc.ymd(2017, 1, 1));
+    assert_eq!((2016, 52), calendar.selected_iso_week());
+    assert_eq!(WeekDay::Sunday, calendar.selected_weekday());
+}
+
+#[test]
+fn test_page_to_available_month() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 6, 17))
+        .earliest_date(Some(Utc.ymd(2017, 6, 15)))
+        .latest_date(Some(Utc.ymd(2017, 6, 20)))
+        .on_boundary(|_, _| {});
+
+    assert_eq!(None, calendar.available_month_offset(-1));
+    assert_eq!(None, calendar.available_month_offset(1));
+
+    match calendar.on_event(Event::Key(Key::PageUp)) {
+        EventResult::Consumed(Some(_)) => {}
+        _ => panic!("Expected a boundary callback"),
+    }
+    assert_eq!(Utc.ymd(2017, 6, 17), calendar.view_date);
+
+    match calendar.on_event(Event::Key(Key::PageDown)) {
+        EventResult::Consumed(Some(_)) => {}
+        _ => panic!("Expected a boundary callback"),
+    }
+    assert_eq!(Utc.ymd(2017, 6, 17), calendar.view_date);
+
+    // Skip fully disabled months
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 6, 17))
+        .disabled_dates(|date: &Date<Utc>| date.month() == 7 || date.month() == 8);
+
+    assert_eq!(Some(3), calendar.available_month_offset(1));
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(2017, 9, 17), calendar.view_date);
+    calendar.on_event(Event::Key(Key::PageUp));
+    assert_eq!(Utc.ymd(2017, 6, 17), calendar.view_date);
+}
+
+#[test]
+fn test_anchor_week() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 8, 16))
+        .fixed_height(false)
+        .anchor_week(Utc.ymd(2017, 8, 16))
+        .anchor_row(4);
+
+    // The week of August 14th is drawn on the fifth row
+    assert_eq!(6, calendar.grid_rows());
+    assert_eq!(Some(Utc.ymd(2017, 7, 17)), calendar.month_grid_start());
+    assert_eq!(Some(4), calendar.selected_row());
+    assert_eq!(Some(Utc.ymd(2017, 8, 14)), calendar.month_grid()[4][0]);
+
+    // Rows above the anchor spill into the previous month
+    calendar.set_anchor_row(5);
+    assert_eq!(Some(Utc.ymd(2017, 7, 10)), calendar.month_grid_start());
+
+    // Ignore anchors which would scroll the cursor out of view
+    calendar.set_anchor_week(Utc.ymd(2017, 11, 6));
+    assert_eq!(Some(Utc.ymd(2017, 7, 31)), calendar.month_grid_start());
+    assert_eq!(5, calendar.grid_rows());
+
+    calendar.set_anchor_week(Utc.ymd(2017, 9, 4));
+    assert_eq!(Some(Utc.ymd(2017, 7, 31)), calendar.month_grid_start());
+    assert_eq!(6, calendar.grid_rows());
+
+    calendar.clear_anchor_week();
+    assert_eq!(5, calendar.grid_rows());
+}
+
+#[test]
+fn test_move_selection() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .latest_date(Some(Utc.ymd(2017, 8, 10)));
+
+    assert_eq!(Utc.ymd(2017, 7, 29), calendar.move_selection(3, 0));
+    assert_eq!(Utc.ymd(2017, 7, 15), calendar.move_selection(0, -2));
+    assert_eq!(Utc.ymd(2017, 8, 10), calendar.move_selection(1, 4));
+
+    assert_eq!(Some(Utc.ymd(2017, 8, 10)), calendar.submit_current());
+    assert_eq!(Utc.ymd(2017, 8, 10), calendar.date());
+
+    calendar.set_view_mode(ViewMode::Year);
+    assert_eq!(None, calendar.submit_current());
+    assert!(calendar.view_mode == ViewMode::Month);
+}
+
+#[test]
+fn test_navigation_mode() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 20))
+        .earliest_date(Some(Utc.ymd(2017, 7, 3)))
+        .latest_date(Some(Utc.ymd(2017, 7, 21)));
+
+    assert_eq!(Utc.ymd(2017, 7, 21), calendar.move_selection(3, 0));
+
+    calendar.set_navigation_mode(NavMode::Wrap);
+    assert_eq!(Utc.ymd(2017, 7, 3), calendar.move_selection(1, 0));
+    assert_eq!(Utc.ymd(2017, 7, 21), calendar.move_selection(0, -1));
+
+    // Wrap to the nearest selectable days
+    calendar.set_disabled_week_days(vec![WeekDay::Monday, WeekDay::Friday]);
+    assert_eq!(Utc.ymd(2017, 7, 4), calendar.move_selection(1, 0));
+    assert_eq!(Utc.ymd(2017, 7, 20), calendar.move_selection(-2, 0));
+
+    // Paging still clamps
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(2017, 7, 20), calendar.view_date);
+}
+
+#[test]
+fn test_decade_size() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .view_mode(ViewMode::Decade)
+        .decade_size(12);
+
+    assert_eq!(2016, calendar.decade_start());
+    assert_eq!("2016 - 2027", calendar.header_title());
+    assert_eq!(15, calendar.visible_years().len());
+    assert_eq!((2015, true), calendar.visible_years()[0]);
+    assert_eq!(10, calendar.content_height());
+
+    calendar.on_event(Event::Key(Key::PageDown));
+    assert_eq!(Utc.ymd(2029, 7, 26), calendar.view_date);
+    assert_eq!(2028, calendar.decade_start());
+
+    calendar.on_event(Event::Key(Key::Up));
+    assert_eq!(Utc.ymd(2025, 7, 26), calendar.view_date);
+    assert_eq!(2016, calendar.decade_start());
+
+    calendar.set_decade_size(10);
+    assert_eq!(2020, calendar.decade_start());
+    assert_eq!(10, calendar.content_height());
+}
+
+#[test]
+fn test_mouse_wheel() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .latest_date(Some(Utc.ymd(2017, 8, 10)))
+        .on_select(|_, _| {});
+    let wheel = |event| Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(0, 0),
+        event,
+    };
+
+    match calendar.on_event(wheel(MouseEvent::WheelUp)) {
+        EventResult::Consumed(Some(_)) => {}
+        _ => panic!("Expected a select callback"),
+    }
+    assert_eq!(Utc.ymd(2017, 6, 26), calendar.view_date);
+
+    calendar.on_event(wheel(MouseEvent::WheelDown));
+    calendar.on_event(wheel(MouseEvent::WheelDown));
+    assert_eq!(Utc.ymd(2017, 8, 10), calendar.view_date);
+
+    calendar.set_view_mode(ViewMode::Decade);
+    calendar.on_event(wheel(MouseEvent::WheelUp));
+    assert_eq!(Utc.ymd(2007, 8, 10), calendar.view_date);
+}
+
+#[test]
+fn test_right_click_ascends() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .highest_view_mode(ViewMode::Year);
+    let right_click = Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(3, 3),
+        event: MouseEvent::Press(MouseButton::Right),
+    };
+
+    match calendar.on_event(right_click.clone()) {
+        EventResult::Consumed(None) => {}
+        _ => panic!("Expected the click to be consumed"),
+    }
+    assert!(calendar.view_mode == ViewMode::Year);
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.view_date);
+
+    match calendar.on_event(right_click) {
+        EventResult::Ignored => {}
+        _ => panic!("Expected the click to be ignored"),
+    }
+    assert!(calendar.view_mode == ViewMode::Year);
+}
+
+#[test]
+fn test_focus_callbacks() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    match calendar.take_focus(Direction::none()) {
+        Ok(EventResult::Consumed(None)) => {}
+        _ => panic!("Expected no focus callback"),
+    }
+    match calendar.on_event(Event::FocusLost) {
+        EventResult::Ignored => {}
+        _ => panic!("Expected the event to be ignored"),
+    }
+
+    calendar.set_on_focus(|_| {});
+    calendar.set_on_blur(|_| {});
+    match calendar.take_focus(Direction::none()) {
+        Ok(EventResult::Consumed(Some(_))) => {}
+        _ => panic!("Expected a focus callback"),
+    }
+    match calendar.on_event(Event::FocusLost) {
+        EventResult::Consumed(Some(_)) => {}
+        _ => panic!("Expected a blur callback"),
+    }
+
+    calendar.set_enabled(false);
+    assert!(calendar.take_focus(Direction::none()).is_err());
+}
+
+#[test]
+fn test_day_of_year_footer() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    assert_eq!(
+        "Day 207 of 365, 158 remaining",
+        calendar.day_of_year_label()
+    );
+    assert_eq!(Vec2::new(20, 8), calendar.required_size(Vec2::new(80, 24)));
+
+    calendar.set_show_day_of_year_footer(true);
+    assert_eq!(Vec2::new(29, 9), calendar.required_size(Vec2::new(80, 24)));
+
+    calendar.set_selected_date(Utc.ymd(2016, 12, 31));
+    assert_eq!("Day 366 of 366, 0 remaining", calendar.day_of_year_label());
+
+    calendar.set_view_mode(ViewMode::Year);
+    assert_eq!(Vec2::new(20, 8), calendar.required_size(Vec2::new(80, 24)));
+}
+
+#[test]
+fn test_focus_date() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .view_mode(ViewMode::Decade)
+        .on_select(|_, _| {})
+        .on_submit(|_, _| {});
+
+    calendar.focus_date(Utc.ymd(2031, 12, 24), ViewMode::Year);
+    assert_eq!(Utc.ymd(2031, 12, 24), calendar.date());
+    assert_eq!(Utc.ymd(2031, 12, 24), calendar.view_date);
+    assert!(calendar.view_mode == ViewMode::Year);
+
+    // Clamp to the available view modes and dates
+    calendar.set_latest_date(Some(Utc.ymd(2030, 1, 1)));
+    calendar.set_highest_view_mode(ViewMode::Year);
+    calendar.focus_date(Utc.ymd(2031, 12, 24), ViewMode::Decade);
+    assert_eq!(Utc.ymd(2030, 1, 1), calendar.date());
+    assert_eq!(Utc.ymd(2030, 1, 1), calendar.view_date);
+    assert!(calendar.view_mode == ViewMode::Year);
+}
+
+#[test]
+fn test_center_in_parent() {
+    let mut calendar =
+        CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26)).center_in_parent(true);
+    assert_eq!(Vec2::new(40, 12), calendar.required_size(Vec2::new(40, 12)));
+    assert_eq!(Vec2::new(20, 8), calendar.size);
+    calendar.layout(Vec2::new(40, 12));
+    assert_eq!(
+        Vec2::new(10, 2),
+        calendar.content_offset(calendar.layout_size)
+    );
+
+    // Clicking July 3rd, two rows below the top of the centered grid
+    calendar.on_event(Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(10, 5),
+        event: MouseEvent::Press(MouseButton::Left),
+    });
+    assert_eq!(Utc.ymd(2017, 7, 3), calendar.view_date);
+
+    // Never shrink below the size of the calendar
+    assert_eq!(Vec2::new(20, 8), calendar.required_size(Vec2::new(10, 4)));
+}
+
+#[test]
+fn test_week_numbering() {
+    // January 2017 starts on a Sunday
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 1, 14))
+        .week_start(WeekDay::Sunday)
+        .week_numbering(WeekNumbering::UsWeekOfYear);
+    assert!(calendar.shows_iso_weeks());
+    assert_eq!(Some(Utc.ymd(2017, 1, 1)), calendar.month_grid_start());
+    assert_eq!(Some(1), calendar.row_week_number(0));
+    assert_eq!(Some(2), calendar.row_week_number(1));
+
+    // The week containing January 1st is week 1 of the following year
+    calendar.set_view_date(Utc.ymd(2017, 12, 14));
+    assert_eq!(Some(Utc.ymd(2017, 11, 26)), calendar.month_grid_start());
+    assert_eq!(Some(48), calendar.row_week_number(0));
+    assert_eq!(Some(52), calendar.row_week_number(4));
+    assert_eq!(Some(1), calendar.row_week_number(5));
+
+    calendar.set_week_numbering(WeekNumbering::Iso);
+    assert_eq!(Some(48), calendar.row_week_number(0));
+    assert_eq!(Some(1), calendar.row_week_number(5));
+
+    calendar.set_week_numbering(WeekNumbering::None);
+    assert!(!calendar.shows_iso_weeks());
+    assert_eq!(None, calendar.row_week_number(0));
+
+    calendar.set_show_iso_weeks(true);
+    assert_eq!(WeekNumbering::Iso, calendar.get_week_numbering());
+}
+
+#[test]
+fn test_with_view_date() {
+    let calendar = CalendarView::<Utc, EnglishLocale>::with_view_date(
+        Utc.ymd(2017, 7, 26),
+        Utc.ymd(1999, 2, 3),
+        Utc.ymd(2017, 7, 3),
+    );
+    assert_eq!(Utc.ymd(1999, 2, 3), calendar.date());
+    assert_eq!(Utc.ymd(2017, 7, 3), calendar.view_date);
+    assert_eq!(Utc.ymd(2017, 7, 26), calendar.today);
+    assert_eq!(
+        ColorStyle::title_primary(),
+        calendar.cell_color(&Utc.ymd(2017, 7, 26), true)
+    );
+}
+
+#[test]
+fn test_type_ahead() {
+    let mut calendar =
+        CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 1, 31)).view_mode(ViewMode::Year);
+
+    calendar.on_event(Event::Char('j'));
+    assert_eq!(Utc.ymd(2017, 6, 30), calendar.view_date);
+    calendar.on_event(Event::Char('u'));
+    assert_eq!(Utc.ymd(2017, 6, 30), calendar.view_date);
+    calendar.on_event(Event::Char('l'));
+    assert_eq!(Utc.ymd(2017, 7, 30), calendar.view_date);
+
+    // Repeating a character cycles through the matching months
+    calendar.on_event(Event::Key(Key::Enter));
+    calendar.set_view_mode(ViewMode::Year);
+    calendar.on_event(Event::Char('J'));
+    assert_eq!(Utc.ymd(2017, 1, 30), calendar.view_date);
+    calendar.on_event(Event::Char('j'));
+    assert_eq!(Utc.ymd(2017, 6, 30), calendar.view_date);
+
+    // Non-character keys reset the search
+    calendar.on_event(Event::Key(Key::Left));
+    assert_eq!(Utc.ymd(2017, 5, 30), calendar.view_date);
+    calendar.on_event(Event::Char('u'));
+    assert_eq!(Utc.ymd(2017, 5, 30), calendar.view_date);
+    match calendar.on_event(Event::Char('x')) {
+        EventResult::Ignored => {}
+        _ => panic!("Expected the event to be ignored"),
+    }
+
+    // Character events are ignored in other view modes
+    calendar.set_view_mode(ViewMode::Month);
+    match calendar.on_event(Event::Char('j')) {
+        EventResult::Ignored => {}
+        _ => panic!("Expected the event to be ignored"),
+    }
+}
+
+#[test]
+fn test_submit_disabled_week_day() {
+    // July 29th 2017 is a Saturday
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 28))
+        .disabled_week_days(vec![WeekDay::Saturday]);
+
+    calendar.on_event(Event::Key(Key::Right));
+    assert_eq!(Utc.ymd(2017, 7, 29), calendar.view_date);
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(Utc.ymd(2017, 7, 28), calendar.date());
+
+    // Clicking the cursor cell takes the same path
+    calendar.on_event(Event::Mouse {
+        offset: Vec2::zero(),
+        position: Vec2::new(15, 6),
+        event: MouseEvent::Press(MouseButton::Left),
+    });
+    assert_eq!(Utc.ymd(2017, 7, 29), calendar.view_date);
+    assert_eq!(Utc.ymd(2017, 7, 28), calendar.date());
+
+    calendar.on_event(Event::Key(Key::Left));
+    calendar.on_event(Event::Key(Key::Enter));
+    assert_eq!(Utc.ymd(2017, 7, 28), calendar.date());
+}
+
+#[test]
+fn test_skip_disabled_on_nav() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 28))
+        .disabled_week_days(vec![WeekDay::Saturday, WeekDay::Sunday])
+        .skip_disabled_on_nav(true);
+
+    calendar.on_event(Event::Key(Key::Right));
+    assert_eq!(Utc.ymd(2017, 7, 31), calendar.view_date);
+    calendar.on_event(Event::Key(Key::Left));
+    assert_eq!(Utc.ymd(2017, 7, 28), calendar.view_date);
+
+    // Moving by whole weeks lands on the same week day again
+    calendar.on_event(Event::Key(Key::Up));
+    assert_eq!(Utc.ymd(2017, 7, 21), calendar.view_date);
+
+    calendar.set_skip_disabled_on_nav(false);
+    calendar.on_event(Event::Key(Key::Right));
+    assert_eq!(Utc.ymd(2017, 7, 22), calendar.view_date);
+}
+
+#[test]
+fn test_type_ahead_today_key() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 1, 15))
+        .view_mode(ViewMode::Year)
+        .view_date(Utc.ymd(2017, 3, 15));
+
+    for c in "oct".chars() {
+        calendar.on_event(Event::Char(c));
+    }
+    assert_eq!(Utc.ymd(2017, 10, 15), calendar.view_date);
+
+    // Without a matching month the today key still applies
+    calendar.on_event(Event::Key(Key::Left));
+    calendar.on_event(Event::Char('t'));
+    assert_eq!(Utc.ymd(2017, 1, 15), calendar.view_date);
+}
+
+#[test]
+fn test_first_last_selectable() {
+    // Bounded
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+        .earliest_date(Some(Utc.ymd(2017, 7, 1)))
+        .latest_date(Some(Utc.ymd(2017, 7, 30)));
+    assert_eq!(Some(Utc.ymd(2017, 7, 1)), calendar.first_selectable());
+    assert_eq!(Some(Utc.ymd(2017, 7, 30)), calendar.last_selectable());
+
+    calendar.set_disabled_week_days(vec![WeekDay::Saturday, WeekDay::Sunday]);
+    assert_eq!(Some(Utc.ymd(2017, 7, 3)), calendar.first_selectable());
+    assert_eq!(Some(Utc.ymd(2017, 7, 28)), calendar.last_selectable());
+
+    // Unbounded
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    assert_eq!(
+        Some(Utc.ymd(2017, 7, 26) - chrono::Duration::days(3660)),
+        calendar.first_selectable()
+    );
+    assert_eq!(
+        Some(Utc.ymd(2017, 7, 26) + chrono::Duration::days(3660)),
+        calendar.last_selectable()
+    );
+
+    // Filtered by a predicate
+    calendar.set_disabled_dates(|date| date.year() != 2016);
+    assert_eq!(Some(Utc.ymd(2016, 1, 1)), calendar.first_selectable());
+    assert_eq!(Some(Utc.ymd(2016, 12, 31)), calendar.last_selectable());
+
+    calendar.set_disabled_dates(|_| true);
+    assert_eq!(None, calendar.first_selectable());
+    assert_eq!(None, calendar.last_selectable());
+}
+
+#[test]
+fn test_monochrome_markers_adjacent() {
+    let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26));
+    let cells = [((1, 2), 2, false, true), ((4, 2), 2, true, false)];
+    assert!(calendar.marker_glyphs(&cells).is_empty());
+
+    // Selection left of the cursor
+    calendar.set_monochrome_markers(true);
+    let glyphs = calendar.marker_glyphs(&cells);
+    assert_eq!(3, glyphs.len());
+    assert!(glyphs.contains(&((0, 2), "[")));
+    assert!(glyphs.contains(&((3, 2), ">")));
+    assert!(glyphs.contains(&((6, 2), "<")));
+
+    // Selection right of the cursor
+    let cells = [((1, 2), 2, true, false), ((4, 2), 2, false, true)];
+    let glyphs = calendar.marker_glyphs(&cells);
+    assert_eq!(3, glyphs.len());
+    assert!(glyphs.contains(&((0, 2), ">")));
+    assert!(glyphs.contains(&((3, 2), "<")));
+    assert!(glyphs.contains(&((6, 2), "]")));
+
+    // Cursor on the selected date
+    let cells = [((1, 2), 2, true, true), ((4, 2), 2, false, false)];
+    assert_eq!(
+        vec![((0, 2), ">"), ((3, 2), "<")],
+        calendar.marker_glyphs(&cells)
+    );
+}
+
+#[test]
+fn test_decade_height() {
+    for &size in &[10, 6] {
+        let mut calendar = CalendarView::<Utc, EnglishLocale>::new(Utc.ymd(2017, 7, 26))
+            .view_mode(ViewMode::Decade)
+            .decade_size(size);
+
+        // Cells are drawn on every other row, starting below the header
+        let cells = calendar.visible_years().len();
+        let last_row = 2 + 2 * ((cells - 1) / 4);
+        assert_eq!(size as usize + 3, cells);
+        assert!(calendar.required_size(Vec2::zero()).y > last_row);
+    }
 }
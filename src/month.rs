@@ -1,5 +1,12 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+// Internal Dependencies ------------------------------------------------------
+use crate::{EnglishLocale, Locale};
+
 /// Enumeration of all months in a year.
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Month {
     /// The month of January.
     January,
@@ -28,12 +35,23 @@ pub enum Month {
 }
 
 impl Month {
-    #[doc(hidden)]
+    /// Returns an iterator over all months in calendar order.
+    pub fn all() -> impl Iterator<Item = Month> {
+        MONTH_LIST.iter().cloned()
+    }
+
+    /// Returns the previous month, wrapping around from January to December.
     pub fn prev(self) -> Self {
         let index: i32 = self.into();
         MONTH_LIST[(((index - 1) + 12) % 12) as usize]
     }
 
+    /// Returns the next month, wrapping around from December to January.
+    pub fn succ(self) -> Self {
+        let index: i32 = self.into();
+        MONTH_LIST[((index + 1) % 12) as usize]
+    }
+
     #[doc(hidden)]
     pub fn number_of_days(self, year: i32) -> i32 {
         match self {
@@ -105,3 +123,10 @@ impl<'a> Into<i32> for Month {
         }
     }
 }
+
+impl fmt::Display for Month {
+    /// Formats the English name, or its short form when using `{:#}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(EnglishLocale::month(*self, !f.alternate()))
+    }
+}
@@ -0,0 +1,178 @@
+// External Dependencies ------------------------------------------------------
+use chrono::prelude::*;
+
+use crate::cursive::direction::Direction;
+use crate::cursive::event::{Event, EventResult};
+use crate::cursive::vec::Vec2;
+use crate::cursive::view::{CannotFocus, View};
+use crate::cursive::With;
+use crate::cursive::{Cursive, Printer};
+
+// Internal Dependencies ------------------------------------------------------
+use crate::{CalendarView, Locale};
+
+/// A [`CalendarView`](struct.CalendarView.html) working with `NaiveDate`s
+/// instead of time zone aware dates.
+///
+/// All remaining configuration is available through
+/// [`inner`](#method.inner) and [`inner_mut`](#method.inner_mut), which
+/// expose the wrapped view using the `Utc` time zone.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate cursive;
+/// # extern crate cursive_calendar_view;
+/// # extern crate chrono;
+/// # use chrono::NaiveDate;
+/// # use cursive_calendar_view::{EnglishLocale, NaiveCalendarView};
+/// # fn main() {
+/// let calendar = NaiveCalendarView::<EnglishLocale>::new(
+///     NaiveDate::from_ymd_opt(2017, 7, 26).unwrap(),
+/// );
+/// # }
+/// ```
+pub struct NaiveCalendarView<L: Locale> {
+    inner: CalendarView<Utc, L>,
+}
+
+impl<L: Locale + Send + Sync + 'static> NaiveCalendarView<L> {
+    /// Creates new `NaiveCalendarView`.
+    pub fn new(today: NaiveDate) -> Self {
+        Self {
+            inner: CalendarView::new(to_date(today)),
+        }
+    }
+
+    /// Returns the wrapped [`CalendarView`](struct.CalendarView.html).
+    pub fn inner(&self) -> &CalendarView<Utc, L> {
+        &self.inner
+    }
+
+    /// Returns the wrapped [`CalendarView`](struct.CalendarView.html) mutably.
+    pub fn inner_mut(&mut self) -> &mut CalendarView<Utc, L> {
+        &mut self.inner
+    }
+
+    /// Returns the currently selected date of this view.
+    pub fn date(&self) -> NaiveDate {
+        self.inner.date().naive_utc()
+    }
+
+    /// Sets the currently selected date of this view.
+    pub fn set_selected_date(&mut self, date: NaiveDate) {
+        self.inner.set_selected_date(to_date(date));
+    }
+
+    /// Sets the currently selected date of this view.
+    ///
+    /// Chainable variant.
+    pub fn selected_date(self, date: NaiveDate) -> Self {
+        self.with(|v| v.set_selected_date(date))
+    }
+
+    /// Sets the visually selected date of this view.
+    pub fn set_view_date(&mut self, date: NaiveDate) {
+        self.inner.set_view_date(to_date(date));
+    }
+
+    /// Sets the visually selected date of this view.
+    ///
+    /// Chainable variant.
+    pub fn view_date(self, date: NaiveDate) -> Self {
+        self.with(|v| v.set_view_date(date))
+    }
+
+    /// Sets and limits the earliest date selectable by this view.
+    pub fn set_earliest_date(&mut self, date: Option<NaiveDate>) {
+        self.inner.set_earliest_date(date.map(to_date));
+    }
+
+    /// Sets and limits the earliest date selectable by this view.
+    ///
+    /// Chainable variant.
+    pub fn earliest_date(self, date: Option<NaiveDate>) -> Self {
+        self.with(|v| v.set_earliest_date(date))
+    }
+
+    /// Sets and limits the latest date selectable by this view.
+    pub fn set_latest_date(&mut self, date: Option<NaiveDate>) {
+        self.inner.set_latest_date(date.map(to_date));
+    }
+
+    /// Sets and limits the latest date selectable by this view.
+    ///
+    /// Chainable variant.
+    pub fn latest_date(self, date: Option<NaiveDate>) -> Self {
+        self.with(|v| v.set_latest_date(date))
+    }
+
+    /// Sets a callback to be used when `<Enter>` is pressed to select a date.
+    pub fn set_on_submit<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, NaiveDate) + Send + Sync + 'static,
+    {
+        self.inner
+            .set_on_submit(move |s, date| cb(s, date.naive_utc()));
+    }
+
+    /// Sets a callback to be used when `<Enter>` is pressed to select a date.
+    ///
+    /// Chainable variant.
+    pub fn on_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, NaiveDate) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_submit(cb))
+    }
+
+    /// Sets a callback to be used when an a new date is visually selected.
+    pub fn set_on_select<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, NaiveDate) + Send + Sync + 'static,
+    {
+        self.inner
+            .set_on_select(move |s, date| cb(s, date.naive_utc()));
+    }
+
+    /// Sets a callback to be used when an a new date is visually selected.
+    ///
+    /// Chainable variant.
+    pub fn on_select<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, NaiveDate) + Send + Sync + 'static,
+    {
+        self.with(|v| v.set_on_select(cb))
+    }
+}
+
+impl<L: Locale + Send + Sync + 'static> View for NaiveCalendarView<L> {
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        self.inner.draw(printer);
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        self.inner.required_size(constraint)
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.inner.layout(size);
+    }
+
+    fn needs_relayout(&self) -> bool {
+        self.inner.needs_relayout()
+    }
+
+    fn take_focus(&mut self, source: Direction) -> Result<EventResult, CannotFocus> {
+        self.inner.take_focus(source)
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        self.inner.on_event(event)
+    }
+}
+
+// Helpers --------------------------------------------------------------------
+fn to_date(date: NaiveDate) -> Date<Utc> {
+    Utc.from_utc_date(&date)
+}
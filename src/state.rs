@@ -0,0 +1,56 @@
+// External Dependencies ------------------------------------------------------
+use chrono::prelude::*;
+
+// Internal Dependencies ------------------------------------------------------
+use crate::{ViewMode, WeekDay};
+
+/// Snapshot of the observable state of a [`CalendarView`](struct.CalendarView.html).
+///
+/// See [`PersistedState`](struct.PersistedState.html) for a snapshot which
+/// can be serialized and restored.
+#[derive(Clone)]
+pub struct CalendarState<T: TimeZone> {
+    /// The currently selected date.
+    pub date: Date<T>,
+    /// The date the cursor is currently placed on.
+    pub view_date: Date<T>,
+    /// The currently active view mode.
+    pub view_mode: ViewMode,
+}
+
+impl<T: TimeZone> PartialEq for CalendarState<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.date == other.date
+            && self.view_date == other.view_date
+            && self.view_mode == other.view_mode
+    }
+}
+
+/// Time zone independent snapshot of the selection and configuration of a
+/// [`CalendarView`](struct.CalendarView.html), e.g. for storing it in a
+/// configuration file.
+///
+/// Implements `Serialize` and `Deserialize` when the `serde` feature is
+/// enabled. Callbacks are not part of the snapshot.
+///
+/// Unlike [`CalendarState`](struct.CalendarState.html), which is bound to the
+/// time zone of its view and passed to the change callbacks, this snapshot
+/// stores plain `NaiveDate`s so it can be restored into any view.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PersistedState {
+    /// The currently selected date.
+    pub date: NaiveDate,
+    /// The date the cursor is currently placed on.
+    pub view_date: NaiveDate,
+    /// The currently active view mode.
+    pub view_mode: ViewMode,
+    /// The day on which weeks start.
+    pub week_start: WeekDay,
+    /// Whether ISO week numbers are shown.
+    pub show_iso_weeks: bool,
+    /// The earliest selectable date.
+    pub earliest_date: Option<NaiveDate>,
+    /// The latest selectable date.
+    pub latest_date: Option<NaiveDate>,
+}
@@ -0,0 +1,57 @@
+// External Dependencies ------------------------------------------------------
+use crate::cursive::theme::ColorStyle;
+
+/// Colors used to draw a [`CalendarView`](struct.CalendarView.html).
+///
+/// Set via [`CalendarView::set_style`](struct.CalendarView.html#method.set_style).
+/// The defaults follow the palette of the active cursive theme.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CalendarStyle {
+    /// Regular cells. Defaults to `ColorStyle::primary()`.
+    pub normal: ColorStyle,
+    /// The cursor while the view is focused. Defaults to `ColorStyle::highlight()`.
+    pub cursor: ColorStyle,
+    /// The selected date, and the cursor while the view is not focused.
+    /// Defaults to `ColorStyle::highlight_inactive()`.
+    pub selected: ColorStyle,
+    /// Today's date. Defaults to `ColorStyle::title_primary()`.
+    pub today: ColorStyle,
+    /// The week containing today's date, if highlighted. Defaults to
+    /// `ColorStyle::title_secondary()`.
+    pub today_week: ColorStyle,
+    /// Days of adjacent months and years of adjacent decades. Defaults to
+    /// `ColorStyle::secondary()`.
+    pub adjacent: ColorStyle,
+    /// Dates which cannot be selected. Defaults to `ColorStyle::tertiary()`.
+    pub unavailable: ColorStyle,
+    /// All cells while the view is disabled. Defaults to `ColorStyle::secondary()`.
+    pub disabled: ColorStyle,
+    /// The days between the start and end of a range. Defaults to
+    /// `ColorStyle::highlight_inactive()`.
+    pub range: ColorStyle,
+    /// The start and end day of a range. Defaults to `ColorStyle::highlight()`.
+    pub range_endpoint: ColorStyle,
+    /// Titles and week day names. Defaults to `ColorStyle::inherit_parent()`.
+    pub header: ColorStyle,
+    /// ISO week numbers. Defaults to `ColorStyle::title_secondary()`.
+    pub iso_week: ColorStyle,
+}
+
+impl Default for CalendarStyle {
+    fn default() -> Self {
+        Self {
+            normal: ColorStyle::primary(),
+            cursor: ColorStyle::highlight(),
+            selected: ColorStyle::highlight_inactive(),
+            today: ColorStyle::title_primary(),
+            today_week: ColorStyle::title_secondary(),
+            adjacent: ColorStyle::secondary(),
+            unavailable: ColorStyle::tertiary(),
+            disabled: ColorStyle::secondary(),
+            range: ColorStyle::highlight_inactive(),
+            range_endpoint: ColorStyle::highlight(),
+            header: ColorStyle::inherit_parent(),
+            iso_week: ColorStyle::title_secondary(),
+        }
+    }
+}
@@ -1,5 +1,12 @@
+// STD Dependencies -----------------------------------------------------------
+use std::fmt;
+
+// Internal Dependencies ------------------------------------------------------
+use crate::{EnglishLocale, Locale};
+
 /// Enumeration of all weekdays.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WeekDay {
     /// Monday.
     Monday,
@@ -17,6 +24,25 @@ pub enum WeekDay {
     Sunday,
 }
 
+impl WeekDay {
+    /// Returns an iterator over all weekdays, starting with Monday.
+    pub fn all() -> impl Iterator<Item = WeekDay> {
+        WEEK_DAY_LIST.iter().cloned()
+    }
+
+    /// Returns the previous weekday, wrapping around from Monday to Sunday.
+    pub fn prev(self) -> Self {
+        let index: i32 = self.into();
+        (index - 1).into()
+    }
+
+    /// Returns the next weekday, wrapping around from Sunday to Monday.
+    pub fn next(self) -> Self {
+        let index: i32 = self.into();
+        (index + 1).into()
+    }
+}
+
 // Statics --------------------------------------------------------------------
 static WEEK_DAY_LIST: [WeekDay; 7] = [
     WeekDay::Monday,
@@ -48,3 +74,10 @@ impl Into<i32> for WeekDay {
         }
     }
 }
+
+impl fmt::Display for WeekDay {
+    /// Formats the English name, or its short form when using `{:#}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(EnglishLocale::week_day(*self, !f.alternate()))
+    }
+}